@@ -0,0 +1,139 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::higher;
+use clippy_utils::hir_utils::SpanlessEq;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if` expressions whose condition loads an atomic, and whose body stores a
+    /// value computed from another load of the same atomic back into it.
+    ///
+    /// ### Why is this bad?
+    /// Another thread may change the atomic between the check, the second load and the store.
+    /// Each operation is atomic, but the sequence isn't, so updates can be lost or the checked
+    /// limit can be exceeded.
+    ///
+    /// ### Known problems
+    /// This is a heuristic. The atomic may be protected by a lock, or only be written by a single
+    /// thread, in which case the sequence is fine.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # const MAX: usize = 10;
+    /// # let counter = AtomicUsize::new(0);
+    /// if counter.load(Ordering::Relaxed) < MAX {
+    ///     counter.store(counter.load(Ordering::Relaxed) + 1, Ordering::Relaxed);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicUsize, Ordering};
+    /// # const MAX: usize = 10;
+    /// # let counter = AtomicUsize::new(0);
+    /// let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+    ///     (count < MAX).then_some(count + 1)
+    /// });
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub ATOMIC_CHECK_THEN_ACT,
+    pedantic,
+    "checking an atomic and then storing a value derived from another load of it"
+}
+declare_lint_pass!(AtomicCheckThenAct => [ATOMIC_CHECK_THEN_ACT]);
+
+impl<'tcx> LateLintPass<'tcx> for AtomicCheckThenAct {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        let Some(higher::If { cond, then, .. }) = higher::If::hir(expr) else {
+            return;
+        };
+        if in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let checked = atomic_loads(cx, cond);
+        if checked.is_empty() {
+            return;
+        }
+
+        let is_checked = |atomic: &Expr<'_>| {
+            checked
+                .iter()
+                .find(|check| SpanlessEq::new(cx).eq_expr(check.0, atomic))
+        };
+        let _: Option<!> = for_each_expr(then, |e| {
+            // A nested `if` checking the same atomic is linted on its own.
+            if let Some(inner) = higher::If::hir(e)
+                && atomic_loads(cx, inner.cond)
+                    .iter()
+                    .any(|load| is_checked(load.0).is_some())
+            {
+                return ControlFlow::Continue(Descend::No);
+            }
+            if let Some((atomic, value)) = atomic_store(cx, e)
+                && let Some(check) = is_checked(atomic)
+                && atomic_loads(cx, value)
+                    .iter()
+                    .any(|load| SpanlessEq::new(cx).eq_expr(load.0, atomic))
+            {
+                let check_span = check.1;
+                span_lint_and_then(
+                    cx,
+                    ATOMIC_CHECK_THEN_ACT,
+                    e.span,
+                    "storing a value derived from a separate load of an atomic that was checked before",
+                    |diag| {
+                        diag.span_note(check_span, "the atomic is checked here");
+                        diag.help(
+                            "use `fetch_add`, `fetch_update` or `compare_exchange` to check and update it atomically",
+                        );
+                    },
+                );
+            }
+            ControlFlow::Continue(Descend::Yes)
+        });
+    }
+}
+
+/// Returns the atomics loaded in `expr`, along with the spans of the loads.
+fn atomic_loads<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Vec<(&'tcx Expr<'tcx>, Span)> {
+    let mut loads = Vec::new();
+    let _: Option<!> = for_each_expr(expr, |e| {
+        if let ExprKind::MethodCall(method, receiver, [_], _) = e.kind
+            && method.ident.name.as_str() == "load"
+            && is_atomic(cx, receiver)
+        {
+            loads.push((receiver, e.span));
+        }
+        ControlFlow::Continue(())
+    });
+    loads
+}
+
+/// If `expr` is a call to `store` on an atomic, returns the atomic and the stored value.
+fn atomic_store<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<(&'tcx Expr<'tcx>, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(method, receiver, [value, _], _) = expr.kind
+        && method.ident.name.as_str() == "store"
+        && is_atomic(cx, receiver)
+    {
+        Some((receiver, value))
+    } else {
+        None
+    }
+}
+
+fn is_atomic(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).peel_refs().kind() {
+        cx.tcx
+            .get_diagnostic_name(adt.did())
+            .is_some_and(|name| name.as_str().starts_with("Atomic"))
+    } else {
+        false
+    }
+}
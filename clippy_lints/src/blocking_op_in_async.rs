@@ -0,0 +1,162 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::match_type;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{fn_def_id, match_def_path, paths};
+use core::ops::ControlFlow;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{AsyncGeneratorKind, Expr, ExprKind, GeneratorKind, MatchSource};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to blocking functions directly inside an `async` function or block:
+    /// * `futures::executor::block_on` and `tokio::runtime::Runtime::block_on`,
+    /// * `std::sync::Barrier::wait`, `std::sync::Condvar::wait` and `std::sync::Condvar::wait_while`,
+    /// * `std::io::Stdin::read_line` and `std::io::Stdout::flush`, unless the
+    ///   `allow-io-blocking-ops` option is set.
+    ///
+    /// If the enclosing `async fn` doesn't contain any `.await`, the lint also points out that it
+    /// could be a regular function.
+    ///
+    /// ### Why is this bad?
+    /// These functions block the current thread. Inside an `async` body, that thread is a worker
+    /// of the executor already running it, so the tasks the blocked call waits on may never get
+    /// to run. On a single-threaded runtime this is a deadlock, and tokio panics when
+    /// `Runtime::block_on` is called from a runtime thread.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// async fn fetch() -> Vec<u8> {
+    ///     futures::executor::block_on(download())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// async fn fetch() -> Vec<u8> {
+    ///     download().await
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub BLOCKING_OP_IN_ASYNC,
+    suspicious,
+    "calling a blocking function inside an `async` body"
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BlockingOpInAsync {
+    allow_io_blocking_ops: bool,
+}
+
+impl BlockingOpInAsync {
+    pub fn new(allow_io_blocking_ops: bool) -> Self {
+        Self { allow_io_blocking_ops }
+    }
+}
+
+impl_lint_pass!(BlockingOpInAsync => [BLOCKING_OP_IN_ASYNC]);
+
+enum BlockingOp {
+    /// `block_on`
+    BlockOn,
+    /// Waiting on a `std::sync` synchronization primitive
+    Wait,
+    /// Reading from or writing to the standard streams
+    Io,
+}
+
+impl BlockingOp {
+    fn of(cx: &LateContext<'_>, expr: &Expr<'_>, def_id: DefId) -> Option<Self> {
+        if match_def_path(cx, def_id, &paths::FUTURES_EXECUTOR_BLOCK_ON)
+            || match_def_path(cx, def_id, &paths::TOKIO_RUNTIME_BLOCK_ON)
+        {
+            Some(Self::BlockOn)
+        } else if match_def_path(cx, def_id, &paths::STD_SYNC_BARRIER_WAIT)
+            || match_def_path(cx, def_id, &paths::STD_SYNC_CONDVAR_WAIT)
+            || match_def_path(cx, def_id, &paths::STD_SYNC_CONDVAR_WAIT_WHILE)
+        {
+            Some(Self::Wait)
+        } else if match_def_path(cx, def_id, &paths::STD_IO_STDIN_READ_LINE) || is_stdout_flush(cx, expr, def_id) {
+            Some(Self::Io)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'tcx> LateLintPass<'tcx> for BlockingOpInAsync {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(..) | ExprKind::MethodCall(..) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(body_id) = cx.enclosing_body
+            && let body = cx.tcx.hir().body(body_id)
+            && let Some(GeneratorKind::Async(async_kind)) = body.generator_kind
+            && let Some(def_id) = fn_def_id(cx, expr)
+            && let Some(op) = BlockingOp::of(cx, expr, def_id)
+            && !(matches!(op, BlockingOp::Io) && self.allow_io_blocking_ops)
+        {
+            let (msg, help) = match op {
+                BlockingOp::BlockOn => (
+                    "calling `block_on` inside an `async` body may deadlock the executor".to_string(),
+                    "`.await` the future instead",
+                ),
+                BlockingOp::Wait => (
+                    format!(
+                        "calling `{}` inside an `async` body blocks the executor",
+                        cx.tcx.item_name(def_id)
+                    ),
+                    "use the synchronization primitives of your async runtime, such as `tokio::sync::Barrier` or \
+                    `tokio::sync::Notify`, instead",
+                ),
+                BlockingOp::Io => (
+                    format!(
+                        "calling `{}` inside an `async` body blocks the executor",
+                        cx.tcx.item_name(def_id)
+                    ),
+                    "use the standard streams of your async runtime, such as `tokio::io::stdin`, instead",
+                ),
+            };
+            let never_awaits = async_kind == AsyncGeneratorKind::Fn && !contains_await(body.value);
+            span_lint_and_then(cx, BLOCKING_OP_IN_ASYNC, expr.span, &msg, |diag| {
+                diag.help(help);
+                if never_awaits {
+                    diag.note(
+                        "this `async fn` never awaits anything, so it could be a regular function, or the blocking \
+                        work could be moved to a blocking thread, e.g. with `tokio::task::spawn_blocking`",
+                    );
+                }
+            });
+        }
+    }
+}
+
+/// Whether `body` contains an `.await`, not counting nested closures and `async` blocks.
+fn contains_await(body: &Expr<'_>) -> bool {
+    for_each_expr(body, |e| {
+        if let ExprKind::Match(_, _, MatchSource::AwaitDesugar) = e.kind {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Whether `expr` calls `Write::flush` on `Stdout`, as a method or as `Write::flush(&mut stdout)`.
+fn is_stdout_flush(cx: &LateContext<'_>, expr: &Expr<'_>, def_id: DefId) -> bool {
+    if cx.tcx.item_name(def_id).as_str() == "flush"
+        && let Some(trait_id) = cx.tcx.trait_of_item(def_id)
+        && cx.tcx.is_diagnostic_item(sym::IoWrite, trait_id)
+        && let ExprKind::MethodCall(_, receiver, ..) | ExprKind::Call(_, [receiver, ..]) = expr.kind
+    {
+        match_type(
+            cx,
+            cx.typeck_results().expr_ty(receiver).peel_refs(),
+            &paths::STD_IO_STDOUT,
+        )
+    } else {
+        false
+    }
+}
@@ -0,0 +1,132 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{find_binding_init, fn_def_id, match_def_path, path_to_local, paths, peel_ptr_casts};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, UpvarCapture};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+use crate::utils::conf;
+use crate::utils::retaining_functions::RetainingFunctions;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for heap allocated closures that borrow locals of the enclosing function, e.g.
+    /// `Box::into_raw(Box::new(|| ..))`, being passed to a function that is known to retain the
+    /// pointer beyond the call, usually as the context of a C callback.
+    ///
+    /// The functions are configured with the `retaining-functions` option, like for
+    /// `stack_ptr_escapes_via_call`.
+    ///
+    /// ### Why is this bad?
+    /// The closure itself lives on the heap, but the locals it borrows are dropped when the
+    /// enclosing function returns. Calling the callback afterwards reads dangling references.
+    ///
+    /// ### Known problems
+    /// Only captures by reference are checked. References moved into a `move` closure aren't
+    /// detected.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let mut count = 0;
+    /// let callback = Box::new(|| count += 1);
+    /// unsafe { register_callback(trampoline::<F>, Box::into_raw(callback).cast()) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let count = Arc::new(AtomicUsize::new(0));
+    /// let callback = Box::new({
+    ///     let count = Arc::clone(&count);
+    ///     move || count.fetch_add(1, Ordering::Relaxed)
+    /// });
+    /// unsafe { register_callback(trampoline::<F>, Box::into_raw(callback).cast()) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub BORROWING_CLOSURE_CALLBACK,
+    suspicious,
+    "passing a closure that borrows locals to a function that retains it"
+}
+
+#[derive(Clone, Debug)]
+pub struct BorrowingClosureCallback {
+    retaining: RetainingFunctions,
+}
+
+impl BorrowingClosureCallback {
+    pub fn new(conf_retaining: Vec<conf::RetainingFunction>) -> Self {
+        Self {
+            retaining: RetainingFunctions::new(conf_retaining),
+        }
+    }
+}
+
+impl_lint_pass!(BorrowingClosureCallback => [BORROWING_CLOSURE_CALLBACK]);
+
+impl<'tcx> LateLintPass<'tcx> for BorrowingClosureCallback {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        self.retaining.resolve(cx);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        for arg in self.retaining.retained_args(cx, expr) {
+            if let Some(closure) = boxed_closure(cx, arg) {
+                check_closure_captures(cx, arg, closure);
+            }
+        }
+    }
+}
+
+fn check_closure_captures(cx: &LateContext<'_>, arg: &Expr<'_>, closure: LocalDefId) {
+    if let Some(capture) = cx
+        .tcx
+        .closure_captures(closure)
+        .iter()
+        .find(|capture| matches!(capture.info.capture_kind, UpvarCapture::ByRef(_)))
+    {
+        let name = capture.to_string(cx.tcx);
+        span_lint_and_then(
+            cx,
+            BORROWING_CLOSURE_CALLBACK,
+            arg.span,
+            &format!("passing a closure that borrows `{name}` to a function that retains it"),
+            |diag| {
+                diag.span_note(capture.get_path_span(cx.tcx), format!("`{name}` is borrowed here"));
+                diag.help(format!(
+                    "the closure dangles once `{name}` goes out of scope; move an owned or shared value into it instead"
+                ));
+            },
+        );
+    }
+}
+
+/// If `arg` is a pointer to a heap allocated closure, e.g. `Box::into_raw(Box::new(|| ..))`,
+/// returns the closure. Looks through local bindings and further boxes.
+fn boxed_closure<'tcx: 'a, 'a>(cx: &LateContext<'tcx>, arg: &'a Expr<'a>) -> Option<LocalDefId> {
+    let call = peel_ptr_casts(arg);
+    let ExprKind::Call(_, [boxed]) = call.kind else {
+        return None;
+    };
+    if !fn_def_id(cx, call).is_some_and(|def_id| {
+        match_def_path(cx, def_id, &paths::BOX_INTO_RAW) || match_def_path(cx, def_id, &paths::BOX_LEAK)
+    }) {
+        return None;
+    }
+    let mut expr = boxed;
+    loop {
+        let mut ty = cx.typeck_results().expr_ty(expr);
+        while ty.is_box() {
+            ty = ty.boxed_ty();
+        }
+        if let ty::Closure(def_id, _) = *ty.kind() {
+            return def_id.as_local();
+        }
+        expr = match expr.kind {
+            // e.g. `Box::new(..)` or `.. as Box<dyn FnMut()>`
+            ExprKind::Call(_, [inner]) | ExprKind::Cast(inner, _) => inner,
+            _ => path_to_local(expr).and_then(|id| find_binding_init(cx, id))?,
+        };
+    }
+}
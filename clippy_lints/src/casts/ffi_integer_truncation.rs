@@ -0,0 +1,106 @@
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{fn_def_id, get_parent_expr, is_rust_abi, path_to_local, path_to_local_id, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+
+use super::{utils, FFI_INTEGER_TRUNCATION};
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &Expr<'_>,
+    cast_expr: &Expr<'_>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
+    if !cast_from.is_integral() || !cast_to.is_integral() {
+        return;
+    }
+    let from_nbits = utils::int_ty_to_nbits(cast_from, cx.tcx);
+    let to_nbits = utils::int_ty_to_nbits(cast_to, cx.tcx);
+    // e.g. `len as isize`, which turns lengths above `isize::MAX` negative
+    let wraps_negative = from_nbits == to_nbits && !cast_from.is_signed() && cast_to.is_signed();
+    if from_nbits < to_nbits || (from_nbits == to_nbits && !wraps_negative) {
+        return;
+    }
+
+    let passed_into = get_parent_expr(cx, expr).is_some_and(|parent| {
+        matches!(parent.kind, ExprKind::Call(_, args) if args.iter().any(|arg| arg.hir_id == expr.hir_id))
+            && has_foreign_abi(cx, parent)
+    });
+    let passed_out = matches!(cast_expr.kind, ExprKind::Call(..)) && has_foreign_abi(cx, cast_expr);
+    if !(passed_into || passed_out)
+        || fits_constant(cx, cast_expr, to_nbits, cast_to.is_signed())
+        || is_range_checked(cx, expr, cast_expr)
+    {
+        return;
+    }
+
+    span_lint_and_help(
+        cx,
+        FFI_INTEGER_TRUNCATION,
+        expr.span,
+        &format!(
+            "casting `{cast_from}` to `{cast_to}` may {} the value {} a foreign function{}",
+            if wraps_negative { "wrap" } else { "truncate" },
+            if passed_into { "passed to" } else { "returned by" },
+            if wraps_negative { " to a negative number" } else { "" },
+        ),
+        None,
+        &format!(
+            "use `{cast_to}::try_from({})` and handle the error instead",
+            snippet(cx, cast_expr.span, "..")
+        ),
+    );
+}
+
+/// Checks if `call` calls a function with a foreign ABI, whether it is declared in an `extern`
+/// block or defined as an `extern "C" fn`.
+fn has_foreign_abi(cx: &LateContext<'_>, call: &Expr<'_>) -> bool {
+    fn_def_id(cx, call).is_some_and(|def_id| !is_rust_abi(cx.tcx.fn_sig(def_id).skip_binder().abi()))
+}
+
+/// Checks if `cast_expr` is a constant that fits in the target type.
+fn fits_constant(cx: &LateContext<'_>, cast_expr: &Expr<'_>, to_nbits: u64, to_signed: bool) -> bool {
+    // shifting the bounds of `i128`/`u128` right avoids overflowing for 128-bit targets
+    let shift = 128 - to_nbits;
+    match constant_full_int(cx, cx.typeck_results(), cast_expr) {
+        Some(FullInt::S(value)) if to_signed => (i128::MIN >> shift..=i128::MAX >> shift).contains(&value),
+        Some(FullInt::S(value)) => u128::try_from(value).is_ok_and(|value| value <= u128::MAX >> shift),
+        Some(FullInt::U(value)) if to_signed => i128::try_from(value).is_ok_and(|value| value <= i128::MAX >> shift),
+        Some(FullInt::U(value)) => value <= u128::MAX >> shift,
+        None => false,
+    }
+}
+
+/// Checks if the range of the value is restricted right in `cast_expr`, e.g. with `x.min(255)`
+/// or `x & 0xff`, or if the local it reads is compared somewhere before the cast.
+fn is_range_checked(cx: &LateContext<'_>, expr: &Expr<'_>, cast_expr: &Expr<'_>) -> bool {
+    match cast_expr.kind {
+        ExprKind::MethodCall(method, ..) if matches!(method.ident.as_str(), "min" | "clamp") => return true,
+        ExprKind::Binary(op, ..) if matches!(op.node, BinOpKind::BitAnd | BinOpKind::Rem | BinOpKind::Shr) => {
+            return true;
+        },
+        _ => {},
+    }
+    let Some(local_id) = path_to_local(cast_expr) else {
+        return false;
+    };
+    let hir = cx.tcx.hir();
+    let body = hir.body(hir.body_owned_by(hir.enclosing_body_owner(expr.hir_id)));
+    for_each_expr(body.value, |e| match e.kind {
+        ExprKind::Binary(op, lhs, rhs)
+            if matches!(op.node, BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge)
+                && e.span.lo() < expr.span.lo()
+                && (path_to_local_id(peel_casts(lhs), local_id) || path_to_local_id(peel_casts(rhs), local_id)) =>
+        {
+            ControlFlow::Break(())
+        },
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
@@ -13,9 +13,12 @@ mod cast_sign_loss;
 mod cast_slice_different_sizes;
 mod cast_slice_from_raw_parts;
 mod char_lit_as_u8;
+mod ffi_integer_truncation;
 mod fn_to_numeric_cast;
 mod fn_to_numeric_cast_any;
 mod fn_to_numeric_cast_with_truncation;
+mod pinned_ref_cast_mut;
+mod ptr_alignment;
 mod ptr_as_ptr;
 mod ptr_cast_constness;
 mod unnecessary_cast;
@@ -665,6 +668,99 @@ declare_clippy_lint! {
     "casting a known floating-point NaN into an integer"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for references that are cast, possibly through several intermediate
+    /// pointer casts, to a pointer whose pointee is more strictly aligned than the
+    /// referenced value, e.g. `&buf as *const [u8; 8] as *const u8 as *const u32`.
+    ///
+    /// ### Why is this bad?
+    /// Unlike an arbitrary raw pointer, the alignment of a reference is known: it is
+    /// the alignment of the referenced type. If that is smaller than the alignment
+    /// of the target type, the resulting pointer is not guaranteed to be aligned and
+    /// dereferencing it is undefined behavior. This commonly happens when
+    /// reinterpreting byte buffers as `#[repr(C)]` structs for FFI.
+    ///
+    /// Casts whose result is immediately checked with `align_offset`, `is_aligned`
+    /// or a `%` computation, or read with `read_unaligned`, are not linted.
+    ///
+    /// ### Known problems
+    /// The last cast of such a chain is a pointer cast, which `cast_ptr_alignment` also
+    /// lints, so enabling both reports it twice.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let buf = [0u8; 8];
+    /// let p = &buf as *const [u8; 8] as *const u8 as *const u32;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let buf = [0u8; 8];
+    /// let value = unsafe { (&buf as *const [u8; 8] as *const u8 as *const u32).read_unaligned() };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub PTR_CAST_INCREASES_ALIGNMENT,
+    pedantic,
+    "casting a reference to a pointer with a stricter alignment than the referenced value"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `as` casts to a narrower integer type, e.g. `n as u32` or `n as c_int` where
+    /// `n: usize`, or from an unsigned to a signed type of the same size, e.g. `n as isize`, on
+    /// values passed to or returned by a function with a foreign ABI, unless the value is a
+    /// constant that fits or was compared before the cast.
+    ///
+    /// ### Why is this bad?
+    /// The cast silently drops the upper bits, or turns large values negative. At an FFI boundary
+    /// the value is usually a length, an offset or a protocol field, and a truncated or negative
+    /// one makes the C side read or write the wrong amount of data.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let n: usize = buf.len();
+    /// unsafe { send_packet(buf.as_ptr(), n as u32) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let n = u32::try_from(buf.len())?;
+    /// unsafe { send_packet(buf.as_ptr(), n) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub FFI_INTEGER_TRUNCATION,
+    suspicious,
+    "narrowing or sign-changing integer casts on values passed to or returned by foreign functions"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `*const T` to `*mut T` casts on pointers created from a shared reference that
+    /// was taken out of a `Pin`, e.g. with `Pin::get_ref` or `Pin::as_ref().get_ref()`.
+    ///
+    /// ### Why is this bad?
+    /// A `Pin<&T>` only grants shared access to the pinned value. Writing through a pointer
+    /// derived from it is undefined behavior, and it also bypasses the guarantees of the
+    /// pinned type, e.g. by moving data out of a self-referential struct.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn reset(node: Pin<&Node>) {
+    ///     let ptr = node.get_ref() as *const Node as *mut Node;
+    ///     unsafe { (*ptr).value = 0 };
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn reset(node: Pin<&mut Node>) {
+    ///     unsafe { node.get_unchecked_mut().value = 0 };
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub PINNED_REF_CAST_MUT,
+    suspicious,
+    "casting a pointer derived from a pinned shared reference to a mutable pointer"
+}
+
 pub struct Casts {
     msrv: Msrv,
 }
@@ -699,6 +795,9 @@ impl_lint_pass!(Casts => [
     CAST_SLICE_FROM_RAW_PARTS,
     AS_PTR_CAST_MUT,
     CAST_NAN_TO_INT,
+    PTR_CAST_INCREASES_ALIGNMENT,
+    FFI_INTEGER_TRUNCATION,
+    PINNED_REF_CAST_MUT,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
@@ -726,6 +825,8 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
             cast_slice_from_raw_parts::check(cx, expr, cast_expr, cast_to, &self.msrv);
             ptr_cast_constness::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
             as_ptr_cast_mut::check(cx, expr, cast_expr, cast_to);
+            pinned_ref_cast_mut::check(cx, expr, cast_expr, cast_from, cast_to);
+            ptr_alignment::check(cx, expr, cast_expr, cast_to);
             fn_to_numeric_cast_any::check(cx, expr, cast_expr, cast_from, cast_to);
             fn_to_numeric_cast::check(cx, expr, cast_expr, cast_from, cast_to);
             fn_to_numeric_cast_with_truncation::check(cx, expr, cast_expr, cast_from, cast_to);
@@ -738,6 +839,7 @@ impl<'tcx> LateLintPass<'tcx> for Casts {
                     cast_sign_loss::check(cx, expr, cast_expr, cast_from, cast_to);
                     cast_abs_to_unsigned::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
                     cast_nan_to_int::check(cx, expr, cast_expr, cast_from, cast_to);
+                    ffi_integer_truncation::check(cx, expr, cast_expr, cast_from, cast_to);
                 }
                 cast_lossless::check(cx, expr, cast_expr, cast_from, cast_to, &self.msrv);
                 cast_enum_constructor::check(cx, expr, cast_expr, cast_from);
@@ -0,0 +1,59 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{expr_or_init, fn_def_id, match_def_path, paths, peel_casts};
+use rustc_hir::{BorrowKind, Expr, ExprKind, LangItem, Mutability, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty, TypeAndMut};
+use rustc_span::Span;
+
+use super::PINNED_REF_CAST_MUT;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, cast_expr: &Expr<'_>, cast_from: Ty<'_>, cast_to: Ty<'_>) {
+    if let ty::RawPtr(TypeAndMut {
+        mutbl: Mutability::Not, ..
+    }) = cast_from.kind()
+        && let ty::RawPtr(TypeAndMut {
+            mutbl: Mutability::Mut, ..
+        }) = cast_to.kind()
+        && let Some(pin_span) = pinned_ref_source(cx, cast_expr)
+    {
+        span_lint_and_then(
+            cx,
+            PINNED_REF_CAST_MUT,
+            expr.span,
+            "casting a pointer derived from a pinned shared reference to a mutable pointer",
+            |diag| {
+                diag.span_note(pin_span, "the shared reference is taken out of the `Pin` here");
+                diag.help("take a `Pin<&mut _>` and use `Pin::get_mut` or `Pin::get_unchecked_mut` instead");
+            },
+        );
+    }
+}
+
+/// If the pointer `expr` was created from a shared reference taken out of a `Pin`, possibly
+/// through casts and local bindings, returns the span where the reference was taken out.
+fn pinned_ref_source<'a>(cx: &LateContext<'_>, mut expr: &'a Expr<'a>) -> Option<Span> {
+    loop {
+        let next = expr_or_init(cx, peel_casts(expr));
+        if next.hir_id == expr.hir_id {
+            break;
+        }
+        expr = next;
+    }
+    match expr.kind {
+        // `pin.get_ref()` or `Pin::get_ref(pin)`
+        ExprKind::MethodCall(..) | ExprKind::Call(..)
+            if fn_def_id(cx, expr).is_some_and(|def_id| match_def_path(cx, def_id, &paths::PIN_GET_REF)) =>
+        {
+            Some(expr.span)
+        },
+        // `&*pin`
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, inner)
+            if let ExprKind::Unary(UnOp::Deref, pin) = inner.kind
+                && is_type_lang_item(cx, cx.typeck_results().expr_ty(pin), LangItem::Pin) =>
+        {
+            Some(expr.span)
+        },
+        _ => None,
+    }
+}
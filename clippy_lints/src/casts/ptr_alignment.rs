@@ -0,0 +1,97 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_c_void;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_expr, path_to_local_id, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, Node, PatKind, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, Ty};
+
+use super::PTR_CAST_INCREASES_ALIGNMENT;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, cast_expr: &'tcx Expr<'_>, cast_to: Ty<'tcx>) {
+    // Only look at the outermost cast of a chain like `&x as *const u8 as *const u32`.
+    if let Some(parent) = get_parent_expr(cx, expr)
+        && matches!(parent.kind, ExprKind::Cast(..))
+    {
+        return;
+    }
+
+    if let ty::RawPtr(to_ptr_ty) = cast_to.kind()
+        && let ty::Ref(_, pointee, _) = cx.typeck_results().expr_ty(peel_casts(cast_expr)).kind()
+        && let Ok(from_layout) = cx.layout_of(*pointee)
+        && let Ok(to_layout) = cx.layout_of(to_ptr_ty.ty)
+        && from_layout.align.abi < to_layout.align.abi
+        && !from_layout.is_zst()
+        && !is_c_void(cx, to_ptr_ty.ty)
+        && !is_alignment_checked(cx, expr)
+    {
+        span_lint_and_help(
+            cx,
+            PTR_CAST_INCREASES_ALIGNMENT,
+            expr.span,
+            &format!(
+                "casting a reference to `{pointee}` to a more-strictly-aligned pointer (`{cast_to}`) ({} < {} bytes)",
+                from_layout.align.abi.bytes(),
+                to_layout.align.abi.bytes(),
+            ),
+            None,
+            "check the alignment with `align_offset` before dereferencing, or use `read_unaligned`",
+        );
+    }
+}
+
+/// Checks whether the pointer produced by `expr` has its alignment checked right away, either
+/// directly (`(p as *const u32).align_offset(4)`) or in the statement following the `let`
+/// binding it, or whether it's read or written with `read_unaligned` or `write_unaligned`.
+fn is_alignment_checked(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let Some(parent) = get_parent_expr(cx, expr) {
+        return matches!(
+            parent.kind,
+            ExprKind::MethodCall(name, receiver, ..)
+                if receiver.hir_id == expr.hir_id
+                    && (is_alignment_method(name.ident.as_str())
+                        || matches!(name.ident.as_str(), "read_unaligned" | "write_unaligned"))
+        );
+    }
+
+    let mut parents = cx.tcx.hir().parent_iter(expr.hir_id);
+    if let Some((_, Node::Local(local))) = parents.next()
+        && let PatKind::Binding(_, local_id, ..) = local.pat.kind
+        && let Some((_, Node::Stmt(stmt))) = parents.next()
+        && let Some((_, Node::Block(block))) = parents.next()
+        && let Some(pos) = block.stmts.iter().position(|s| s.hir_id == stmt.hir_id)
+    {
+        let next = match block.stmts.get(pos + 1) {
+            Some(next) => match next.kind {
+                StmtKind::Local(local) => local.init,
+                StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                StmtKind::Item(_) => None,
+            },
+            None => block.expr,
+        };
+        return next.is_some_and(|next| checks_alignment_of(next, local_id));
+    }
+
+    false
+}
+
+fn checks_alignment_of(expr: &Expr<'_>, local_id: HirId) -> bool {
+    for_each_expr(expr, |e| match e.kind {
+        ExprKind::MethodCall(name, receiver, ..)
+            if is_alignment_method(name.ident.as_str()) && path_to_local_id(peel_casts(receiver), local_id) =>
+        {
+            ControlFlow::Break(())
+        },
+        ExprKind::Binary(op, lhs, _) if op.node == BinOpKind::Rem && path_to_local_id(peel_casts(lhs), local_id) => {
+            ControlFlow::Break(())
+        },
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
+
+fn is_alignment_method(name: &str) -> bool {
+    matches!(name, "align_offset" | "is_aligned" | "is_aligned_to")
+}
@@ -0,0 +1,61 @@
+use crate::utils::byte_buffers::{byte_buffer_origin, is_nul_terminated_literal};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{expr_or_init, match_def_path, path_def_id, paths, peel_casts};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `CStr::from_ptr` with a pointer obtained from a byte slice,
+    /// array or `Vec<u8>` via `as_ptr`.
+    ///
+    /// ### Why is this bad?
+    /// `CStr::from_ptr` reads until it finds a NUL byte. Rust byte buffers are not
+    /// NUL-terminated, so unless a terminator was added manually this reads out of
+    /// bounds, which is undefined behavior.
+    ///
+    /// ### Known problems
+    /// Buffers that had a NUL byte pushed at runtime are linted as well.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # use std::ffi::CStr;
+    /// let bytes = vec![b'h', b'i'];
+    /// let s = unsafe { CStr::from_ptr(bytes.as_ptr().cast()) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::ffi::CStr;
+    /// let bytes = vec![b'h', b'i', 0];
+    /// let s = CStr::from_bytes_with_nul(&bytes).unwrap();
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub CSTR_FROM_UNTERMINATED_PTR,
+    nursery,
+    "creating a `CStr` from a pointer to a Rust byte buffer"
+}
+declare_lint_pass!(CStrFromUnterminatedPtr => [CSTR_FROM_UNTERMINATED_PTR]);
+
+impl<'tcx> LateLintPass<'tcx> for CStrFromUnterminatedPtr {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(func, [arg]) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(def_id) = path_def_id(cx, func)
+            && match_def_path(cx, def_id, &paths::CSTR_FROM_PTR)
+            && let ExprKind::MethodCall(method, recv, [], _) = expr_or_init(cx, peel_casts(arg)).kind
+            && matches!(method.ident.as_str(), "as_ptr" | "as_mut_ptr" | "cast")
+            && let Some(recv) = byte_buffer_origin(cx, method.ident.as_str(), recv)
+            && !is_nul_terminated_literal(recv)
+        {
+            span_lint_and_help(
+                cx,
+                CSTR_FROM_UNTERMINATED_PTR,
+                expr.span,
+                "creating a `CStr` from a pointer to a byte buffer that is not known to be NUL-terminated",
+                None,
+                "use `CStr::from_bytes_with_nul` to check the buffer for a NUL terminator",
+            );
+        }
+    }
+}
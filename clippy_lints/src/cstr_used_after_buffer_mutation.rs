@@ -0,0 +1,107 @@
+use crate::utils::byte_buffers::byte_buffer_origin;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::first_after;
+use clippy_utils::{
+    expr_or_init, get_enclosing_block, match_def_path, path_def_id, path_to_local, path_to_local_id, paths,
+    peel_blocks, peel_casts, peel_hir_expr_refs,
+};
+use rustc_hir::{BorrowKind, Expr, ExprKind, HirId, Local, Mutability, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a `CStr` or a byte slice created with `CStr::from_ptr` or
+    /// `slice::from_raw_parts` over a local byte buffer, which is used after the buffer was
+    /// modified.
+    ///
+    /// ### Why is this bad?
+    /// The `CStr` or slice created from a pointer doesn't borrow the buffer, so the compiler
+    /// accepts modifying the buffer while it's alive. Clearing or growing a `Vec` can free or move
+    /// its data, and any write changes what the `CStr` is supposed to hold.
+    ///
+    /// ### Known problems
+    /// The uses are ordered by their position in the source, so uses in a loop that come before
+    /// the modification aren't detected.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let mut buf = vec![b'a', 0];
+    /// let name = unsafe { CStr::from_ptr(buf.as_ptr().cast()) };
+    /// buf.clear();
+    /// println!("{}", name.to_str()?);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let mut buf = vec![b'a', 0];
+    /// let name = CStr::from_bytes_with_nul(&buf)?;
+    /// println!("{}", name.to_str()?);
+    /// buf.clear();
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub CSTR_USED_AFTER_BUFFER_MUTATION,
+    suspicious,
+    "using a `CStr` or slice created from a pointer into a buffer after modifying the buffer"
+}
+declare_lint_pass!(CStrUsedAfterBufferMutation => [CSTR_USED_AFTER_BUFFER_MUTATION]);
+
+impl<'tcx> LateLintPass<'tcx> for CStrUsedAfterBufferMutation {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let PatKind::Binding(_, view_id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && !local.span.from_expansion()
+            && let ExprKind::Call(func, [ptr, ..]) = peel_blocks(init).kind
+            && let Some(def_id) = path_def_id(cx, func)
+            && (match_def_path(cx, def_id, &paths::CSTR_FROM_PTR)
+                || match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS))
+            && let ExprKind::MethodCall(method, recv, [], _) = expr_or_init(cx, peel_casts(ptr)).kind
+            && matches!(method.ident.as_str(), "as_ptr" | "as_mut_ptr" | "cast")
+            && let Some(recv) = byte_buffer_origin(cx, method.ident.as_str(), recv)
+            && let Some(buf_id) = path_to_local(peel_hir_expr_refs(recv).0)
+            && let Some(block) = get_enclosing_block(cx, local.hir_id)
+            && let Some(mutation) = first_after(block, local.span, |e| is_buffer_mutation(cx, e, buf_id))
+            && let Some(use_span) = first_after(block, mutation, |e| path_to_local_id(e, view_id))
+        {
+            span_lint_and_then(
+                cx,
+                CSTR_USED_AFTER_BUFFER_MUTATION,
+                use_span,
+                "using a value created from a pointer into a buffer after the buffer was modified",
+                |diag| {
+                    diag.span_note(mutation, "the buffer is modified here");
+                    diag.help("finish using the value before modifying the buffer, or copy its contents out");
+                },
+            );
+        }
+    }
+}
+
+/// Whether `expr` modifies the local `buf_id`, by calling a method taking `&mut self`, taking a
+/// mutable reference or assigning to it.
+fn is_buffer_mutation(cx: &LateContext<'_>, expr: &Expr<'_>, buf_id: HirId) -> bool {
+    match expr.kind {
+        ExprKind::MethodCall(_, receiver, ..) => {
+            path_to_local_id(receiver, buf_id)
+                && cx
+                    .typeck_results()
+                    .type_dependent_def_id(expr.hir_id)
+                    .and_then(|id| {
+                        cx.tcx
+                            .fn_sig(id)
+                            .instantiate_identity()
+                            .skip_binder()
+                            .inputs()
+                            .first()
+                            .copied()
+                    })
+                    .is_some_and(|self_ty| matches!(self_ty.kind(), ty::Ref(_, _, Mutability::Mut)))
+        },
+        ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, inner) => path_to_local_id(inner, buf_id),
+        ExprKind::Assign(lhs, ..) | ExprKind::AssignOp(_, lhs, _) => match lhs.kind {
+            ExprKind::Index(base, ..) => path_to_local_id(base, buf_id),
+            _ => path_to_local_id(lhs, buf_id),
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,85 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::first_after;
+use clippy_utils::{get_enclosing_block, path_to_local, path_to_local_id};
+use rustc_hir::{Expr, ExprKind, HirId, Local, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for raw pointers into the buffer of a local `CString`, e.g. from `as_ptr()`, that
+    /// are used after `into_raw()` was called on the `CString`.
+    ///
+    /// ### Why is this bad?
+    /// `into_raw()` gives up the ownership of the buffer, usually to hand it over to foreign code,
+    /// which may free or reuse it at any time. Pointers taken out before are no longer backed by
+    /// anything Rust owns, and using them is a use after free waiting to happen.
+    ///
+    /// ### Known problems
+    /// The uses are ordered by their position in the source, so uses in a loop that come before
+    /// the `into_raw()` call aren't detected.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let name = CString::new("foo")?;
+    /// let ptr = name.as_ptr();
+    /// ffi_take_ownership(name.into_raw());
+    /// ffi_print(ptr);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let name = CString::new("foo")?;
+    /// ffi_print(name.as_ptr());
+    /// ffi_take_ownership(name.into_raw());
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub CSTRING_USED_AFTER_INTO_RAW,
+    suspicious,
+    "using a pointer into a `CString` after its ownership was given up with `into_raw`"
+}
+declare_lint_pass!(CStringUsedAfterIntoRaw => [CSTRING_USED_AFTER_INTO_RAW]);
+
+impl<'tcx> LateLintPass<'tcx> for CStringUsedAfterIntoRaw {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let PatKind::Binding(_, ptr_id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && !in_external_macro(cx.sess(), local.span)
+            && cx.typeck_results().expr_ty(init).is_unsafe_ptr()
+            && let Some(cstring_id) = cstring_local(cx, init)
+            && let Some(block) = get_enclosing_block(cx, local.hir_id)
+            && let Some(into_raw_span) = first_after(block, local.span, |e| is_into_raw(e, cstring_id))
+            && let Some(use_span) = first_after(block, into_raw_span, |e| path_to_local_id(e, ptr_id))
+        {
+            span_lint_and_then(
+                cx,
+                CSTRING_USED_AFTER_INTO_RAW,
+                use_span,
+                "using a pointer into a `CString` after its ownership was given up with `into_raw`",
+                |diag| {
+                    diag.span_note(into_raw_span, "the ownership of the buffer is given up here");
+                    diag.help("use the buffer before calling `into_raw`, or use the pointer returned by `into_raw`");
+                },
+            );
+        }
+    }
+}
+
+/// Returns the local `CString` that `expr` takes a pointer out of, e.g. `s` in
+/// `s.as_bytes().as_ptr()`.
+fn cstring_local(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> Option<HirId> {
+    while let ExprKind::MethodCall(_, receiver, [], _) = expr.kind {
+        expr = receiver;
+    }
+    path_to_local(expr).filter(|_| is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::cstring_type))
+}
+
+fn is_into_raw(expr: &Expr<'_>, cstring_id: HirId) -> bool {
+    matches!(
+        expr.kind,
+        ExprKind::MethodCall(method, receiver, [], _)
+            if method.ident.name.as_str() == "into_raw" && path_to_local_id(receiver, cstring_id)
+    )
+}
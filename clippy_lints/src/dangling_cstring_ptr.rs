@@ -0,0 +1,93 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_parent_node;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::{Expr, ExprKind, Node, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for pointers taken with `as_ptr()` out of a temporary `CString`, such as
+    /// `CString::new(s)?` or the result of a function returning a `CString`, which are bound to
+    /// a local or assigned to a place.
+    ///
+    /// `CString`s unwrapped with `unwrap` or `expect` are left to rustc's
+    /// `temporary_cstring_as_ptr` lint.
+    ///
+    /// ### Why is this bad?
+    /// The temporary `CString` is dropped at the end of the statement, which frees its buffer.
+    /// The pointer dangles from the next statement on, and passing it to C is a use after free.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let name = CString::new(name)?.as_ptr();
+    /// unsafe { libc::puts(name) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let name = CString::new(name)?;
+    /// unsafe { libc::puts(name.as_ptr()) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub DANGLING_CSTRING_PTR,
+    correctness,
+    "keeping a pointer into a temporary `CString` past the end of the statement"
+}
+declare_lint_pass!(DanglingCStringPtr => [DANGLING_CSTRING_PTR]);
+
+impl<'tcx> LateLintPass<'tcx> for DanglingCStringPtr {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::MethodCall(method, receiver, [], _) = expr.kind
+            && method.ident.name.as_str() == "as_ptr"
+            && !in_external_macro(cx.sess(), expr.span)
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(receiver), sym::cstring_type)
+            && !matches!(
+                receiver.kind,
+                ExprKind::Path(_) | ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(UnOp::Deref, _)
+            )
+            && !is_unwrapped(receiver)
+            && let Some(target) = outliving_target(cx, expr)
+        {
+            span_lint_and_then(
+                cx,
+                DANGLING_CSTRING_PTR,
+                expr.span,
+                "taking a pointer into a temporary `CString` which is dropped at the end of the statement",
+                |diag| {
+                    diag.span_note(target, "the pointer is kept here, past the end of the statement");
+                    diag.span_help(receiver.span, "bind the `CString` to a local with `let` first");
+                },
+            );
+        }
+    }
+}
+
+/// If the pointer `expr`, possibly after casts, initializes a local or is assigned to a place,
+/// returns the span of the local or place.
+fn outliving_target(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Span> {
+    let mut hir_id = expr.hir_id;
+    loop {
+        match get_parent_node(cx.tcx, hir_id)? {
+            Node::Expr(parent) if let ExprKind::Cast(..) = parent.kind => hir_id = parent.hir_id,
+            Node::Expr(parent)
+                if let ExprKind::Assign(lhs, rhs, _) = parent.kind
+                    && rhs.hir_id == hir_id =>
+            {
+                return Some(lhs.span);
+            },
+            Node::Local(local) => return Some(local.pat.span),
+            _ => return None,
+        }
+    }
+}
+
+/// Checks for `.unwrap()` and `.expect(..)`, on which rustc's `temporary_cstring_as_ptr` lint
+/// already fires.
+fn is_unwrapped(receiver: &Expr<'_>) -> bool {
+    matches!(
+        receiver.kind,
+        ExprKind::MethodCall(method, ..) if matches!(method.ident.name.as_str(), "unwrap" | "expect")
+    )
+}
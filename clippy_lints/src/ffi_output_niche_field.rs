@@ -0,0 +1,119 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{ForeignItem, ForeignItemKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty, TypeAndMut};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `#[repr(C)]` structs with a `bool`, `char` or fieldless `enum` field, which
+    /// are passed to a foreign function through a mutable pointer or reference, i.e. as an
+    /// output parameter.
+    ///
+    /// ### Why is this bad?
+    /// Only some bit patterns are valid for these types, e.g. `0` and `1` for a `bool`. The C
+    /// side sees a plain integer and may write any other value to it, and reading the field
+    /// afterwards is undefined behavior.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[repr(C)]
+    /// struct Status {
+    ///     ready: bool,
+    /// }
+    ///
+    /// extern "C" {
+    ///     fn get_status(status: *mut Status);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[repr(C)]
+    /// struct Status {
+    ///     ready: u8,
+    /// }
+    ///
+    /// extern "C" {
+    ///     fn get_status(status: *mut Status);
+    /// }
+    ///
+    /// impl Status {
+    ///     fn ready(&self) -> bool {
+    ///         self.ready != 0
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub FFI_OUTPUT_NICHE_FIELD,
+    suspicious,
+    "a field with invalid bit patterns in a struct filled by a foreign function"
+}
+
+#[derive(Default)]
+pub struct FfiOutputNicheField {
+    /// The structs already linted, as they may be filled by several functions.
+    linted: DefIdSet,
+}
+
+impl_lint_pass!(FfiOutputNicheField => [FFI_OUTPUT_NICHE_FIELD]);
+
+impl<'tcx> LateLintPass<'tcx> for FfiOutputNicheField {
+    fn check_foreign_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ForeignItem<'_>) {
+        let ForeignItemKind::Fn(decl, ..) = item.kind else {
+            return;
+        };
+        if in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        let sig = cx.tcx.fn_sig(item.owner_id).instantiate_identity().skip_binder();
+        for (param, &param_ty) in decl.inputs.iter().zip(sig.inputs()) {
+            let (ty::RawPtr(TypeAndMut {
+                ty: pointee,
+                mutbl: Mutability::Mut,
+            })
+            | ty::Ref(_, pointee, Mutability::Mut)) = *param_ty.kind()
+            else {
+                continue;
+            };
+            let ty::Adt(adt, args) = *pointee.kind() else {
+                continue;
+            };
+            if !adt.is_struct() || !adt.repr().c() || !adt.did().is_local() || !self.linted.insert(adt.did()) {
+                continue;
+            }
+            for field in adt.all_fields() {
+                let field_ty = field.ty(cx.tcx, args);
+                if has_invalid_bit_patterns(field_ty) {
+                    span_lint_hir_and_then(
+                        cx,
+                        FFI_OUTPUT_NICHE_FIELD,
+                        cx.tcx.hir().local_def_id_to_hir_id(field.did.expect_local()),
+                        cx.tcx.def_span(field.did),
+                        &format!(
+                            "the field `{}` of type `{field_ty}` is filled by a foreign function, which may write an \
+                            invalid value to it",
+                            field.name
+                        ),
+                        |diag| {
+                            diag.span_note(
+                                param.span,
+                                format!("`{pointee}` is filled by `{}` through this parameter", item.ident),
+                            );
+                            diag.help("use an integer type such as `u8` instead, and check its value after the call");
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn has_invalid_bit_patterns(ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::Bool | ty::Char => true,
+        ty::Adt(adt, _) => adt.is_enum() && adt.is_payloadfree(),
+        _ => false,
+    }
+}
@@ -0,0 +1,168 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{def_path_def_ids, is_rust_abi};
+use rustc_hir::def_id::{DefIdSet, LocalDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl, FnRetTy, ForeignItem, ForeignItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty, TypeAndMut};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for parameters and return types of `extern` functions, both declared in `extern`
+    /// blocks and defined in Rust, whose type has no layout C code can rely on: `String`, `Vec`,
+    /// references to `str`, slices and trait objects, `Option` of anything but a non-nullable
+    /// pointer, and tuples.
+    ///
+    /// Types can be accepted with the `ffi-safe-types` option.
+    ///
+    /// ### Why is this bad?
+    /// The foreign code sees these values as whatever it declared on its side, while Rust is free
+    /// to lay them out differently, or to pass a pointer and a length where C expects a single
+    /// pointer. The compiler's `improper_ctypes` lints cover most of these types, but not all of
+    /// them, and can't be configured.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// extern "C" {
+    ///     fn log_message(msg: &str);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// extern "C" {
+    ///     fn log_message(msg: *const c_char, len: usize);
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub FFI_UNSAFE_PARAM_TYPE,
+    restriction,
+    "using a type without a stable layout in the signature of an `extern` function"
+}
+
+#[derive(Clone, Debug)]
+pub struct FfiUnsafeParamType {
+    safe_types: Vec<String>,
+    safe_def_ids: DefIdSet,
+}
+
+impl FfiUnsafeParamType {
+    pub fn new(safe_types: Vec<String>) -> Self {
+        Self {
+            safe_types,
+            safe_def_ids: DefIdSet::default(),
+        }
+    }
+
+    fn check_decl(&self, cx: &LateContext<'_>, decl: &FnDecl<'_>, def_id: LocalDefId) {
+        let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+        let output = match decl.output {
+            FnRetTy::Return(hir_ty) => Some((hir_ty, sig.output())),
+            FnRetTy::DefaultReturn(_) => None,
+        };
+        for (hir_ty, ty) in decl.inputs.iter().zip(sig.inputs().iter().copied()).chain(output) {
+            if let Some(reason) = self.unsafe_reason(cx, ty) {
+                span_lint_and_note(
+                    cx,
+                    FFI_UNSAFE_PARAM_TYPE,
+                    hir_ty.span,
+                    &format!("`{ty}` is not FFI-safe"),
+                    None,
+                    reason,
+                );
+            }
+        }
+    }
+
+    /// Returns why the layout of `ty` can't be relied on across FFI, if it can't.
+    fn unsafe_reason(&self, cx: &LateContext<'_>, ty: Ty<'_>) -> Option<&'static str> {
+        match *ty.kind() {
+            ty::Adt(adt, _) if self.safe_def_ids.contains(&adt.did()) => None,
+            ty::Adt(..) if is_type_diagnostic_item(cx, ty, sym::String) => {
+                Some("`String` has no stable layout, pass a pointer from `CString::into_raw` or `CStr::as_ptr` instead")
+            },
+            ty::Adt(..) if is_type_diagnostic_item(cx, ty, sym::Vec) => {
+                Some("`Vec` has no stable layout, pass a pointer to its data and its length instead")
+            },
+            ty::Adt(_, args) if is_type_diagnostic_item(cx, ty, sym::Option) => {
+                let inner = args.type_at(0);
+                if is_non_nullable_ptr(cx, inner) {
+                    self.unsafe_reason(cx, inner)
+                } else {
+                    Some(
+                        "`Option` only has a stable layout around a reference, a `Box`, a function pointer, \
+                        `NonNull` or a `NonZero` integer",
+                    )
+                }
+            },
+            ty::Adt(..) if ty.is_box() => fat_ptr_reason(ty.boxed_ty()),
+            ty::Ref(_, pointee, _) | ty::RawPtr(TypeAndMut { ty: pointee, .. }) => fat_ptr_reason(pointee),
+            ty::Tuple(elems) if !elems.is_empty() => {
+                Some("tuples have no stable layout, use a `#[repr(C)]` struct instead")
+            },
+            _ => None,
+        }
+    }
+}
+
+impl_lint_pass!(FfiUnsafeParamType => [FFI_UNSAFE_PARAM_TYPE]);
+
+impl<'tcx> LateLintPass<'tcx> for FfiUnsafeParamType {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.safe_types {
+            let segs: Vec<_> = path.split("::").collect();
+            self.safe_def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if !in_external_macro(cx.sess(), span) && kind.header().is_some_and(|header| !is_rust_abi(header.abi)) {
+            self.check_decl(cx, decl, def_id);
+        }
+    }
+
+    fn check_foreign_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ForeignItem<'_>) {
+        if let ForeignItemKind::Fn(decl, ..) = item.kind
+            && !in_external_macro(cx.sess(), item.span)
+            && !is_rust_abi(cx.tcx.hir().get_foreign_abi(item.hir_id()))
+        {
+            self.check_decl(cx, decl, item.owner_id.def_id);
+        }
+    }
+}
+
+/// Whether `ty` is a pointer `Option<ty>` is guaranteed to represent `None` with null for.
+fn is_non_nullable_ptr(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    match ty.kind() {
+        ty::Ref(..) | ty::FnPtr(_) => true,
+        ty::Adt(adt, _) => ty.is_box() || cx.tcx.has_attr(adt.did(), sym::rustc_nonnull_optimization_guaranteed),
+        _ => false,
+    }
+}
+
+/// Returns why a pointer to `pointee` can't be passed across FFI, if it's a fat pointer.
+fn fat_ptr_reason(pointee: Ty<'_>) -> Option<&'static str> {
+    match pointee.kind() {
+        ty::Str => Some(
+            "a pointer to `str` is made of a pointer and a length, pass a `*const c_char` or a pointer and a \
+            length instead",
+        ),
+        ty::Slice(_) => Some("a pointer to a slice is made of a pointer and a length, pass them separately instead"),
+        ty::Dynamic(..) => Some(
+            "a pointer to a trait object is made of a data pointer and a vtable pointer, whose layout isn't \
+            stable",
+        ),
+        _ => None,
+    }
+}
@@ -0,0 +1,173 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{
+    def_path_def_ids, fn_def_id, get_enclosing_block, get_parent_expr, get_parent_node, match_def_path,
+    path_to_local_id, paths,
+};
+use core::ops::ControlFlow;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Block, Expr, ExprKind, HirId, Node, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for values owning an OS resource, such as a `File`, a `TcpStream` or a
+    /// `MutexGuard`, being passed to `mem::forget` or `Box::leak`, or wrapped in a
+    /// `ManuallyDrop` that is never dropped.
+    ///
+    /// A value owns a resource if its type is one of the types configured with the
+    /// `owning-resource-types` option, contains one of them, or implements `Drop` and has a field
+    /// containing one of them.
+    ///
+    /// ### Why is this bad?
+    /// The destructor of these types releases the resource. Skipping it leaks the file
+    /// descriptor, socket or process handle, or keeps the lock held forever.
+    ///
+    /// ### Known problems
+    /// Leaking is sometimes intended, e.g. after the ownership of a file descriptor was handed
+    /// over to foreign code. `#[allow]` the lint in that case.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # use std::fs::File;
+    /// let file = File::open("foo.txt").unwrap();
+    /// std::mem::forget(file);
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # use std::fs::File;
+    /// let file = File::open("foo.txt").unwrap();
+    /// drop(file);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub FORGET_OWNING_RESOURCE,
+    suspicious,
+    "leaking a value that owns an OS resource or a lock"
+}
+
+#[derive(Clone, Debug)]
+pub struct ForgetOwningResource {
+    conf_types: Vec<String>,
+    def_ids: DefIdSet,
+}
+
+impl ForgetOwningResource {
+    pub fn new(conf_types: Vec<String>) -> Self {
+        Self {
+            conf_types,
+            def_ids: DefIdSet::default(),
+        }
+    }
+
+    /// Checks if `ty` is or contains one of the configured types. If `check_fields` is set, the
+    /// fields of types implementing `Drop` are checked as well.
+    fn owns_resource<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>, check_fields: bool) -> bool {
+        match ty.kind() {
+            ty::Adt(adt, args) => {
+                self.def_ids.contains(&adt.did())
+                    || args.types().any(|ty| self.owns_resource(cx, ty, check_fields))
+                    || (check_fields
+                        && adt.has_dtor(cx.tcx)
+                        && adt
+                            .all_fields()
+                            .any(|field| self.owns_resource(cx, field.ty(cx.tcx, args), false)))
+            },
+            ty::Tuple(tys) => tys.iter().any(|ty| self.owns_resource(cx, ty, check_fields)),
+            ty::Array(ty, _) | ty::Slice(ty) => self.owns_resource(cx, *ty, check_fields),
+            _ => false,
+        }
+    }
+}
+
+impl_lint_pass!(ForgetOwningResource => [FORGET_OWNING_RESOURCE]);
+
+impl<'tcx> LateLintPass<'tcx> for ForgetOwningResource {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.conf_types {
+            let segs: Vec<_> = path.split("::").collect();
+            self.def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, [arg]) = expr.kind
+            && let Some(def_id) = fn_def_id(cx, expr)
+            && !in_external_macro(cx.sess(), expr.span)
+        {
+            let arg_ty = cx.typeck_results().expr_ty(arg);
+            let (what, ty) = if cx.tcx.is_diagnostic_item(sym::mem_forget, def_id) {
+                ("`mem::forget`", arg_ty)
+            } else if match_def_path(cx, def_id, &paths::BOX_LEAK) && arg_ty.is_box() {
+                ("`Box::leak`", arg_ty.boxed_ty())
+            } else if match_def_path(cx, def_id, &paths::MANUALLY_DROP_NEW) && is_never_dropped(cx, expr) {
+                ("`ManuallyDrop::new` without dropping it later", arg_ty)
+            } else {
+                return;
+            };
+            if self.owns_resource(cx, ty, true) {
+                span_lint_and_help(
+                    cx,
+                    FORGET_OWNING_RESOURCE,
+                    expr.span,
+                    &format!("using {what} on `{ty}` leaks the resource it owns"),
+                    None,
+                    "drop the value instead, or take out the raw resource explicitly, e.g. with `into_raw_fd`",
+                );
+            }
+        }
+    }
+}
+
+/// Checks if the `ManuallyDrop` created by `expr` is discarded, or bound to a local that is
+/// never dropped or moved elsewhere.
+fn is_never_dropped(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match get_parent_node(cx.tcx, expr.hir_id) {
+        Some(Node::Stmt(_)) => true,
+        Some(Node::Local(local)) => match local.pat.kind {
+            PatKind::Wild => true,
+            PatKind::Binding(_, local_id, _, None) => {
+                get_enclosing_block(cx, local.hir_id).is_some_and(|block| !is_released(cx, block, local_id))
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Checks if the `ManuallyDrop` bound to `local_id` is dropped, taken out or moved somewhere else
+/// in `block`.
+fn is_released<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, local_id: HirId) -> bool {
+    for_each_expr(block, |e| {
+        if path_to_local_id(e, local_id) && is_released_by(cx, e) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Checks if the use `e` of a `ManuallyDrop` local drops it, takes out its value or moves it.
+fn is_released_by(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let Some(Node::Expr(parent)) = get_parent_node(cx.tcx, e.hir_id) else {
+        // e.g. the tail expression of a block
+        return true;
+    };
+    match parent.kind {
+        // `ManuallyDrop::drop(&mut x)` or `ManuallyDrop::take(&mut x)`
+        ExprKind::AddrOf(..) => get_parent_expr(cx, parent)
+            .and_then(|call| fn_def_id(cx, call))
+            .is_some_and(|def_id| {
+                match_def_path(cx, def_id, &paths::MANUALLY_DROP_DROP)
+                    || match_def_path(cx, def_id, &paths::MANUALLY_DROP_TAKE)
+            }),
+        ExprKind::MethodCall(_, receiver, ..) => receiver.hir_id != e.hir_id,
+        ExprKind::Assign(target, ..) => target.hir_id != e.hir_id,
+        ExprKind::Field(..) | ExprKind::Index(..) | ExprKind::Unary(UnOp::Deref, _) => false,
+        _ => true,
+    }
+}
@@ -0,0 +1,117 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{fn_def_id, match_def_path, path_to_local, paths, peel_ptr_casts, stack_local_address};
+use rustc_hir::{Expr, ExprKind, ImplItem, ImplItemKind, Item, ItemKind, Node, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for slices created with `slice::from_raw_parts` over the data of a local or a
+    /// temporary, e.g. `vec.as_ptr()`, being stored in a place that lives longer than that
+    /// data: a binding annotated with a `'static` lifetime, a field, or the return value.
+    ///
+    /// ### Why is this bad?
+    /// The data is freed when the local goes out of scope, or at the end of the statement for a
+    /// temporary. The slice doesn't borrow from it, so the compiler can't catch that the slice
+    /// dangles afterwards.
+    ///
+    /// ### Known problems
+    /// Only pointers obtained directly in the arguments of `from_raw_parts` are checked.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn bytes() -> &'static [u8] {
+    ///     let data = vec![1, 2, 3];
+    ///     unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn bytes() -> Vec<u8> {
+    ///     vec![1, 2, 3]
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub FROM_RAW_PARTS_OUTLIVES_OWNER,
+    suspicious,
+    "storing a slice created with `from_raw_parts` in a place that outlives its data"
+}
+declare_lint_pass!(FromRawPartsOutlivesOwner => [FROM_RAW_PARTS_OUTLIVES_OWNER]);
+
+impl<'tcx> LateLintPass<'tcx> for FromRawPartsOutlivesOwner {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, [ptr, _]) = expr.kind
+            && !expr.span.from_expansion()
+            && fn_def_id(cx, expr).is_some_and(|def_id| {
+                match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS)
+                    || match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS_MUT)
+            })
+            && let Some(owner) = data_owner(cx, ptr)
+            && let Some(place) = longer_lived_place(cx, expr)
+        {
+            span_lint_and_help(
+                cx,
+                FROM_RAW_PARTS_OUTLIVES_OWNER,
+                expr.span,
+                &format!("slice over the data of {owner} is stored in {place}"),
+                None,
+                &format!("the slice dangles once {owner} is dropped; store an owned copy of the data instead"),
+            );
+        }
+    }
+}
+
+/// If `ptr` points into data owned by a local or a temporary, e.g. `vec.as_ptr()` or
+/// `&array as *const _`, returns a description of the owner.
+fn data_owner(cx: &LateContext<'_>, ptr: &Expr<'_>) -> Option<String> {
+    if let Some(local) = stack_local_address(cx, ptr) {
+        return Some(format!("the local `{}`", cx.tcx.hir().name(local)));
+    }
+    if let ExprKind::MethodCall(method, receiver, [], _) = peel_ptr_casts(ptr).kind
+        && matches!(method.ident.as_str(), "as_ptr" | "as_mut_ptr")
+        // Data behind a reference or a raw pointer isn't owned by the receiver.
+        && !cx.typeck_results().expr_ty(receiver).is_any_ptr()
+    {
+        if let Some(local) = path_to_local(receiver) {
+            return Some(format!("the local `{}`", cx.tcx.hir().name(local)));
+        } else if !receiver.is_syntactic_place_expr() {
+            return Some("a temporary".to_owned());
+        }
+    }
+    None
+}
+
+/// Returns a description of the place the value of `expr` is stored in, if that place lives
+/// longer than the enclosing function's locals. Looks through the blocks `expr` is the tail
+/// expression of, e.g. an `unsafe` block.
+fn longer_lived_place(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    let mut child_id = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Block(block) if block.expr.is_some_and(|tail| tail.hir_id == child_id) => {},
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::Block(..) => {},
+                ExprKind::Assign(target, ..) if matches!(target.kind, ExprKind::Field(..)) => return Some("a field"),
+                ExprKind::Ret(_) => return Some("the return value"),
+                _ => return None,
+            },
+            Node::Local(local) => {
+                return local
+                    .ty
+                    .is_some_and(|ty| matches!(ty.kind, TyKind::Ref(lifetime, _) if lifetime.is_static()))
+                    .then_some("a `'static` binding");
+            },
+            Node::ExprField(_) => return Some("a field"),
+            Node::Item(Item {
+                kind: ItemKind::Fn(..), ..
+            })
+            | Node::ImplItem(ImplItem {
+                kind: ImplItemKind::Fn(..),
+                ..
+            }) => return Some("the return value"),
+            _ => return None,
+        }
+        child_id = parent_id;
+    }
+    None
+}
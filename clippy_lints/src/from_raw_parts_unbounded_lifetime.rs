@@ -0,0 +1,99 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{fn_def_id, match_def_path, paths, peel_blocks};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, ExprKind, FnDecl};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, GenericArgKind};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions returning the result of `slice::from_raw_parts` or
+    /// `slice::from_raw_parts_mut` with a lifetime parameter of the function that doesn't appear
+    /// in any of its inputs, although one of them is a reference.
+    ///
+    /// ### Why is this bad?
+    /// A lifetime only used in the return type is chosen freely by the caller, so the slice may
+    /// be kept alive after the value owning the buffer is dropped or modified. This is a common
+    /// soundness bug in bindings wrapping a foreign buffer.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// impl Buffer {
+    ///     pub fn data<'a>(&self) -> &'a [u8] {
+    ///         unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// impl Buffer {
+    ///     pub fn data(&self) -> &[u8] {
+    ///         unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub FROM_RAW_PARTS_UNBOUNDED_LIFETIME,
+    suspicious,
+    "returning a slice created from raw parts with a lifetime not tied to any input"
+}
+declare_lint_pass!(FromRawPartsUnboundedLifetime => [FROM_RAW_PARTS_UNBOUNDED_LIFETIME]);
+
+impl<'tcx> LateLintPass<'tcx> for FromRawPartsUnboundedLifetime {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure) || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+        let value = peel_blocks(body.value);
+        if let ty::Ref(region, ..) = *sig.output().kind()
+            && is_own_lifetime(cx, region, def_id)
+            && let ref_inputs = sig.inputs().iter().filter(|ty| ty.is_ref()).count()
+            && ref_inputs > 0
+            && !sig.inputs().iter().any(|ty| {
+                ty.walk()
+                    .any(|arg| matches!(arg.unpack(), GenericArgKind::Lifetime(r) if r == region))
+            })
+            && let ExprKind::Call(..) = value.kind
+            && let Some(callee) = fn_def_id(cx, value)
+            && (match_def_path(cx, callee, &paths::SLICE_FROM_RAW_PARTS)
+                || match_def_path(cx, callee, &paths::SLICE_FROM_RAW_PARTS_MUT))
+        {
+            span_lint_and_then(
+                cx,
+                FROM_RAW_PARTS_UNBOUNDED_LIFETIME,
+                decl.output.span(),
+                &format!("returning a slice created from raw parts with the unbounded lifetime `{region}`"),
+                |diag| {
+                    diag.span_note(value.span, "the slice is created here");
+                    if ref_inputs == 1 {
+                        diag.help(format!("remove `{region}` to tie the slice to the borrowed input"));
+                    } else {
+                        diag.help(format!("tie `{region}` to the input owning the buffer"));
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Whether `region` is a lifetime parameter of the function `def_id` itself.
+fn is_own_lifetime(cx: &LateContext<'_>, region: ty::Region<'_>, def_id: LocalDefId) -> bool {
+    match *region {
+        ty::ReLateBound(..) => true,
+        ty::ReEarlyBound(param) => cx.tcx.parent(param.def_id) == def_id.to_def_id(),
+        _ => false,
+    }
+}
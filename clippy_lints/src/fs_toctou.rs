@@ -0,0 +1,211 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{def_path_def_ids, fn_def_id, match_def_path, path_to_local, paths};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
+use rustc_hir::{Body, BorrowKind, Expr, ExprKind, FnDecl, HirId, Mutability, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a path being passed to a filesystem operation after it was checked, e.g. with
+    /// `Path::exists` or `fs::metadata`, when the path wasn't reassigned in between.
+    ///
+    /// The checking and using functions are configured with the `fs-toctou-check-functions` and
+    /// `fs-toctou-use-functions` options.
+    ///
+    /// ### Why is this bad?
+    /// The filesystem can change between the check and the use. The result of the check may
+    /// no longer hold when the path is used, which is a classic time-of-check to time-of-use
+    /// race and can be exploited when another user controls the directory.
+    ///
+    /// ### Known problems
+    /// The check and the use are matched in the order they appear in the function, regardless
+    /// of whether the use actually depends on the result of the check.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # use std::fs::File;
+    /// # use std::path::Path;
+    /// # let path = Path::new("config.toml");
+    /// if path.exists() {
+    ///     let file = File::open(path);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # use std::fs::File;
+    /// # use std::path::Path;
+    /// # let path = Path::new("config.toml");
+    /// if let Ok(file) = File::open(path) {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub FS_TOCTOU,
+    restriction,
+    "using a filesystem path after checking it"
+}
+
+#[derive(Clone, Debug)]
+pub struct FsToctou {
+    conf_check_fns: Vec<String>,
+    conf_use_fns: Vec<String>,
+    check_fns: DefIdSet,
+    use_fns: DefIdSet,
+}
+
+impl FsToctou {
+    pub fn new(conf_check_fns: Vec<String>, conf_use_fns: Vec<String>) -> Self {
+        Self {
+            conf_check_fns,
+            conf_use_fns,
+            check_fns: DefIdSet::default(),
+            use_fns: DefIdSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(FsToctou => [FS_TOCTOU]);
+
+impl<'tcx> LateLintPass<'tcx> for FsToctou {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for (conf_fns, fns) in [
+            (&self.conf_check_fns, &mut self.check_fns),
+            (&self.conf_use_fns, &mut self.use_fns),
+        ] {
+            for path in conf_fns {
+                let segs: Vec<_> = path.split("::").collect();
+                fns.extend(def_path_def_ids(cx, &segs));
+            }
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        _: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        _: LocalDefId,
+    ) {
+        if span.from_expansion() {
+            return;
+        }
+        let mut visitor = CheckThenUseVisitor {
+            cx,
+            lint: self,
+            checked: FxHashMap::default(),
+        };
+        visitor.visit_expr(body.value);
+    }
+}
+
+struct CheckThenUseVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    lint: &'a FsToctou,
+    /// The path locals that were checked, and where they were checked.
+    checked: FxHashMap<HirId, Span>,
+}
+
+impl<'tcx> CheckThenUseVisitor<'_, 'tcx> {
+    fn check_call(&mut self, expr: &'tcx Expr<'tcx>, receiver: Option<&'tcx Expr<'tcx>>, args: &'tcx [Expr<'tcx>]) {
+        let Some(def_id) = fn_def_id(self.cx, expr) else {
+            return;
+        };
+        let mut path_locals = receiver
+            .into_iter()
+            .chain(args)
+            .filter_map(|arg| path_local(self.cx, arg));
+        if self.lint.check_fns.contains(&def_id) {
+            for local in path_locals {
+                self.checked.insert(local, expr.span);
+            }
+        } else if self.lint.use_fns.contains(&def_id)
+            && let Some((local, check_span)) =
+                path_locals.find_map(|local| self.checked.remove(&local).map(|span| (local, span)))
+        {
+            let name = self.cx.tcx.hir().name(local);
+            span_lint_and_then(
+                self.cx,
+                FS_TOCTOU,
+                expr.span,
+                &format!("`{name}` is used after being checked, but the filesystem may have changed in between"),
+                |diag| {
+                    diag.span_note(check_span, "the path is checked here");
+                    diag.help("handle the error of the operation itself instead of checking the path beforehand");
+                },
+            );
+        }
+    }
+
+    /// Forgets about `expr` having been checked if it is a checked local.
+    fn reset(&mut self, expr: &Expr<'_>) {
+        if let Some(local) = path_to_local(expr) {
+            self.checked.remove(&local);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for CheckThenUseVisitor<'_, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        walk_expr(self, expr);
+        match expr.kind {
+            ExprKind::Call(_, args) => self.check_call(expr, None, args),
+            ExprKind::MethodCall(_, receiver, args, _) => {
+                self.check_call(expr, Some(receiver), args);
+                // e.g. `path.push("..")`
+                let mutably_borrowed = self
+                    .cx
+                    .typeck_results()
+                    .expr_adjustments(receiver)
+                    .iter()
+                    .any(|adjust| {
+                        matches!(
+                            adjust.kind,
+                            Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+                        )
+                    });
+                if mutably_borrowed {
+                    self.reset(receiver);
+                }
+            },
+            ExprKind::Assign(target, ..)
+            | ExprKind::AssignOp(_, target, _)
+            | ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, target) => self.reset(target),
+            _ => {},
+        }
+    }
+}
+
+/// Returns the local holding the path passed as `expr`, looking through borrows and
+/// conversions such as `Path::new(path)` or `path.as_path()`.
+fn path_local(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> Option<HirId> {
+    loop {
+        match expr.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, _, inner) | ExprKind::Unary(UnOp::Deref, inner) => expr = inner,
+            ExprKind::MethodCall(method, receiver, [], _)
+                if matches!(
+                    method.ident.as_str(),
+                    "as_path" | "as_ref" | "clone" | "to_owned" | "to_path_buf"
+                ) =>
+            {
+                expr = receiver;
+            },
+            ExprKind::Call(_, [arg])
+                if fn_def_id(cx, expr).is_some_and(|def_id| match_def_path(cx, def_id, &paths::PATH_NEW)) =>
+            {
+                expr = arg;
+            },
+            _ => return path_to_local(expr),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::utils::conf::AddressRange;
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::expr_or_init;
+use rustc_hir::{Expr, ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for dereferences of pointers created by casting a constant integer, such as
+    /// `*(1 as *const i32)`, directly or through a local binding.
+    ///
+    /// Addresses of memory-mapped registers can be allowed with the `mmio-address-ranges`
+    /// configuration.
+    ///
+    /// ### Why is this bad?
+    /// Outside of memory-mapped I/O, no object lives at a hardcoded address, so the pointer is
+    /// almost certainly dangling and dereferencing it is undefined behavior.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// let p = 0x10 as *const u32;
+    /// let value = unsafe { *p };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let x = 0x10u32;
+    /// let p = &x as *const u32;
+    /// let value = unsafe { *p };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub HARDCODED_ADDRESS_DEREF,
+    suspicious,
+    "dereferencing a pointer cast from a constant integer"
+}
+
+#[derive(Clone)]
+pub struct HardcodedAddressDeref {
+    mmio_address_ranges: Vec<AddressRange>,
+}
+
+impl HardcodedAddressDeref {
+    pub fn new(mmio_address_ranges: Vec<AddressRange>) -> Self {
+        Self { mmio_address_ranges }
+    }
+}
+
+impl_lint_pass!(HardcodedAddressDeref => [HARDCODED_ADDRESS_DEREF]);
+
+impl<'tcx> LateLintPass<'tcx> for HardcodedAddressDeref {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Unary(UnOp::Deref, ptr) = expr.kind
+            && !expr.span.from_expansion()
+            && cx.typeck_results().expr_ty(ptr).is_unsafe_ptr()
+            && let cast = expr_or_init(cx, ptr)
+            && let ExprKind::Cast(addr, _) = cast.kind
+            && cx.typeck_results().expr_ty(addr).is_integral()
+            && let Some(Constant::Int(address)) = constant(cx, cx.typeck_results(), addr)
+            && !self.mmio_address_ranges.iter().any(|range| range.contains(address))
+        {
+            span_lint_and_then(
+                cx,
+                HARDCODED_ADDRESS_DEREF,
+                expr.span,
+                &format!("dereferencing a pointer to the hardcoded address `{address:#x}`"),
+                |diag| {
+                    if cast.hir_id != ptr.hir_id {
+                        diag.span_note(cast.span, "the address is cast to a pointer here");
+                    }
+                    diag.help("if this is a memory-mapped register, add its range to `mmio-address-ranges`");
+                },
+            );
+        }
+    }
+}
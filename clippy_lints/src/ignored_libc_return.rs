@@ -0,0 +1,88 @@
+use crate::utils::fn_set::FnSet;
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_hir::{Expr, ExprKind, Node, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to C functions such as `write`, `read` or `close` whose return value
+    /// is discarded, either as a statement or with `let _ = ..`.
+    ///
+    /// The functions are configured with the `ignored-libc-return-functions` option. Besides
+    /// the configured paths, `extern` declarations with the same name are checked as well.
+    ///
+    /// ### Why is this bad?
+    /// These functions report errors and partial reads or writes only through their return
+    /// value. Ignoring it silently drops the error.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// unsafe { libc::close(fd) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// if unsafe { libc::close(fd) } == -1 {
+    ///     return Err(std::io::Error::last_os_error());
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub IGNORED_LIBC_RETURN,
+    pedantic,
+    "ignoring the return value of a C function that reports errors through it"
+}
+
+#[derive(Clone, Debug)]
+pub struct IgnoredLibcReturn {
+    functions: Vec<String>,
+    checked_fns: FnSet,
+}
+
+impl IgnoredLibcReturn {
+    pub fn new(functions: Vec<String>) -> Self {
+        Self {
+            functions,
+            checked_fns: FnSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(IgnoredLibcReturn => [IGNORED_LIBC_RETURN]);
+
+impl<'tcx> LateLintPass<'tcx> for IgnoredLibcReturn {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        self.checked_fns.extend(cx, &self.functions);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(..) = expr.kind
+            && !expr.span.from_expansion()
+            && is_discarded(cx, expr)
+            && self.checked_fns.contains_callee(cx, expr)
+        {
+            span_lint_and_help(
+                cx,
+                IGNORED_LIBC_RETURN,
+                expr.span,
+                "the return value of this call is ignored",
+                None,
+                "check the returned value for errors",
+            );
+        }
+    }
+}
+
+/// Checks if the value of `expr` is thrown away, looking through a wrapping `unsafe` block.
+fn is_discarded(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let mut child = expr.hir_id;
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Stmt(stmt) => return matches!(stmt.kind, StmtKind::Semi(_) | StmtKind::Expr(_)),
+            Node::Local(local) => return matches!(local.pat.kind, PatKind::Wild),
+            Node::Block(block) if block.expr.is_some_and(|e| e.hir_id == child) => {},
+            Node::Expr(e) if matches!(e.kind, ExprKind::Block(..)) => child = e.hir_id,
+            _ => return false,
+        }
+    }
+    false
+}
@@ -0,0 +1,128 @@
+use crate::utils::fn_set::FnSet;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::hir_utils::SpanlessEq;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{following_exprs, higher, path_to_local, peel_blocks_and_casts, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, Expr, ExprKind, HirId, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for buffers being sliced up to their capacity after a C function such as `read`
+    /// filled them, instead of up to the number of bytes it returned.
+    ///
+    /// The functions are configured with the `read-into-buffer-functions` option. They must
+    /// take the buffer as their second argument and its capacity as their third, like `read`
+    /// does. Besides the configured paths, `extern` declarations with the same name are checked
+    /// as well.
+    ///
+    /// ### Why is this bad?
+    /// These functions may fill only a prefix of the buffer. Everything after the returned
+    /// length is stale or uninitialized data.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), cap) };
+    /// process(&buf[..cap]);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), cap) };
+    /// process(&buf[..n as usize]);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub IGNORED_READ_LENGTH,
+    suspicious,
+    "slicing a buffer up to its capacity after a C function read into it"
+}
+
+#[derive(Clone, Debug)]
+pub struct IgnoredReadLength {
+    read_functions: Vec<String>,
+    read_fns: FnSet,
+}
+
+impl IgnoredReadLength {
+    pub fn new(read_functions: Vec<String>) -> Self {
+        Self {
+            read_functions,
+            read_fns: FnSet::default(),
+        }
+    }
+
+    /// Returns the arguments of the call to a read function in `expr`, looking through `unsafe`
+    /// blocks and casts.
+    fn read_call_args<'tcx>(&self, cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx [Expr<'tcx>]> {
+        let expr = peel_blocks_and_casts(expr);
+        if let ExprKind::Call(_, args) = expr.kind
+            && self.read_fns.contains_callee(cx, expr)
+        {
+            Some(args)
+        } else {
+            None
+        }
+    }
+}
+
+impl_lint_pass!(IgnoredReadLength => [IGNORED_READ_LENGTH]);
+
+impl<'tcx> LateLintPass<'tcx> for IgnoredReadLength {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        self.read_fns.extend(cx, &self.read_functions);
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if let StmtKind::Local(local) = stmt.kind
+                && let PatKind::Binding(_, _, len, _) = local.pat.kind
+                && let Some(init) = local.init
+                && !init.span.from_expansion()
+                && let Some([_, buf_arg, cap_arg, ..]) = self.read_call_args(cx, init)
+                && let Some(buf) = buffer_local(buf_arg)
+            {
+                let cap = peel_casts(cap_arg);
+                for e in following_exprs(block, i).chain(block.expr) {
+                    let _: Option<!> = for_each_expr(e, |e| {
+                        if let ExprKind::Index(base, index, _) = e.kind
+                            && !e.span.from_expansion()
+                            && path_to_local(base.peel_borrows()) == Some(buf)
+                            && let Some(higher::Range { end: Some(end), .. }) = higher::Range::hir(index)
+                            && SpanlessEq::new(cx).eq_expr(peel_casts(end), cap)
+                        {
+                            span_lint_and_then(
+                                cx,
+                                IGNORED_READ_LENGTH,
+                                e.span,
+                                "slicing the buffer up to its capacity after a read that may have filled it partially",
+                                |diag| {
+                                    diag.span_note(init.span, "the number of bytes read is returned here");
+                                    diag.help(format!("slice the buffer up to `{len}` instead"));
+                                },
+                            );
+                        }
+                        ControlFlow::Continue(())
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Returns the local whose memory is passed as `expr`, e.g. `buf` for `buf.as_mut_ptr().cast()`
+/// or `&mut buf as *mut _`.
+fn buffer_local<'a>(mut expr: &'a Expr<'a>) -> Option<HirId> {
+    loop {
+        expr = peel_casts(expr);
+        match expr.kind {
+            ExprKind::MethodCall(method, receiver, [], _)
+                if matches!(method.ident.as_str(), "as_mut_ptr" | "cast" | "cast_mut") =>
+            {
+                expr = receiver;
+            },
+            ExprKind::AddrOf(_, _, inner) => expr = inner,
+            _ => return path_to_local(expr),
+        }
+    }
+}
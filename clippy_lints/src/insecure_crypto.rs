@@ -0,0 +1,145 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{DefId, DefIdSet};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, GenericArgsRef, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to, and construction of, cryptographic primitives that are known to be
+    /// broken, such as MD5, SHA-1, DES, RC4 and the ECB block cipher mode.
+    ///
+    /// The functions and types are configured with the `insecure-crypto-paths` option, which
+    /// defaults to the relevant items of the `md-5`, `md5`, `sha1`, `des`, `rc4` and `ecb` crates.
+    /// Configured type aliases are resolved to the type they stand for, e.g. `sha1::Sha1` to
+    /// `CoreWrapper<Sha1Core>`.
+    ///
+    /// ### Why is this bad?
+    /// Practical attacks exist against all of these. Hashes can be forged and encrypted data
+    /// can be recovered.
+    ///
+    /// ### Known problems
+    /// Using these algorithms outside of a security context, e.g. for non-adversarial
+    /// checksums or to talk to legacy protocols, is linted as well.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let digest = md5::compute(data);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use sha2::{Digest, Sha256};
+    /// let digest = Sha256::digest(data);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub INSECURE_CRYPTO,
+    restriction,
+    "use of a broken cryptographic primitive"
+}
+
+#[derive(Clone, Debug)]
+pub struct InsecureCrypto {
+    conf_paths: Vec<String>,
+    def_ids: DefIdSet,
+    /// Generic wrappers that configured type aliases resolve to, e.g. `CoreWrapper` for
+    /// `sha1::Sha1`, which is an alias for `CoreWrapper<Sha1Core>`.
+    wrappers: DefIdSet,
+}
+
+impl InsecureCrypto {
+    pub fn new(conf_paths: Vec<String>) -> Self {
+        Self {
+            conf_paths,
+            def_ids: DefIdSet::default(),
+            wrappers: DefIdSet::default(),
+        }
+    }
+
+    fn is_insecure_ty(&self, ty: Ty<'_>) -> bool {
+        let ty::Adt(adt, args) = ty.kind() else {
+            return false;
+        };
+        self.def_ids.contains(&adt.did())
+            || (self.wrappers.contains(&adt.did())
+                && wrapped_adt(args).is_some_and(|inner| self.def_ids.contains(&inner)))
+    }
+}
+
+impl_lint_pass!(InsecureCrypto => [INSECURE_CRYPTO]);
+
+impl<'tcx> LateLintPass<'tcx> for InsecureCrypto {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.conf_paths {
+            let segs: Vec<_> = path.split("::").collect();
+            for def_id in def_path_def_ids(cx, &segs) {
+                if cx.tcx.def_kind(def_id) == DefKind::TyAlias
+                    && let ty::Adt(adt, args) = *cx.tcx.type_of(def_id).instantiate_identity().kind()
+                {
+                    if let Some(inner) = wrapped_adt(args) {
+                        self.wrappers.insert(adt.did());
+                        self.def_ids.insert(inner);
+                    } else {
+                        self.def_ids.insert(adt.did());
+                    }
+                } else {
+                    self.def_ids.insert(def_id);
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        let is_insecure = match expr.kind {
+            // Calls to configured functions, and associated functions of configured types such as
+            // `Md5::new()` or `Sha1::digest(data)`.
+            ExprKind::Call(func, _) => fn_def_id(cx, expr).is_some_and(|def_id| {
+                self.def_ids.contains(&def_id)
+                    || self_ty(cx, func, def_id).is_some_and(|self_ty| self.is_insecure_ty(self_ty))
+            }),
+            ExprKind::MethodCall(..) => fn_def_id(cx, expr).is_some_and(|def_id| self.def_ids.contains(&def_id)),
+            ExprKind::Struct(..) => self.is_insecure_ty(cx.typeck_results().expr_ty(expr)),
+            // Unit and tuple struct constructors
+            ExprKind::Path(ref qpath) => matches!(
+                cx.qpath_res(qpath, expr.hir_id),
+                Res::Def(DefKind::Ctor(..), ctor_id) if self.def_ids.contains(&cx.tcx.parent(ctor_id))
+            ),
+            _ => false,
+        };
+        if is_insecure {
+            span_lint_and_help(
+                cx,
+                INSECURE_CRYPTO,
+                expr.span,
+                "use of a broken cryptographic primitive",
+                None,
+                "use a secure algorithm instead, such as SHA-256 for hashing or AES-GCM for encryption",
+            );
+        }
+    }
+}
+
+/// Returns the `Self` type of the associated function `def_id` called through `func`.
+fn self_ty<'tcx>(cx: &LateContext<'tcx>, func: &Expr<'_>, def_id: DefId) -> Option<Ty<'tcx>> {
+    if let Some(impl_id) = cx.tcx.impl_of_method(def_id) {
+        Some(cx.tcx.type_of(impl_id).instantiate_identity())
+    } else if cx.tcx.trait_of_item(def_id).is_some() {
+        cx.typeck_results().node_args(func.hir_id).types().next()
+    } else {
+        None
+    }
+}
+
+/// Returns the ADT passed as the first type argument of a generic wrapper, e.g. `Sha1Core` in
+/// `CoreWrapper<Sha1Core>`.
+fn wrapped_adt(args: GenericArgsRef<'_>) -> Option<DefId> {
+    match args.types().next()?.kind() {
+        ty::Adt(inner, _) => Some(inner.did()),
+        _ => None,
+    }
+}
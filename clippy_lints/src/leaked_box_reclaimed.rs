@@ -0,0 +1,70 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{fn_def_id, get_enclosing_block, match_def_path, path_to_local_id, paths, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::{ExprKind, Local, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for references returned by `Box::leak` and bound to a local, which are later
+    /// passed to `Box::from_raw` in the same block.
+    ///
+    /// ### Why is this bad?
+    /// Leaking a box and reclaiming it later is sound, but it makes the ownership of the memory
+    /// hard to follow: the leaked reference looks valid for the rest of the program, and any
+    /// use of it, or of a copy of it, after the reclaim is a use after free. Such code deserves
+    /// a careful review.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let config: &'static mut u32 = Box::leak(Box::new(42));
+    /// // ...
+    /// drop(unsafe { Box::from_raw(config) });
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let config = Box::into_raw(Box::new(42));
+    /// // ...
+    /// drop(unsafe { Box::from_raw(config) });
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub LEAKED_BOX_RECLAIMED,
+    restriction,
+    "reclaiming a `Box` that was leaked with `Box::leak`"
+}
+declare_lint_pass!(LeakedBoxReclaimed => [LEAKED_BOX_RECLAIMED]);
+
+impl<'tcx> LateLintPass<'tcx> for LeakedBoxReclaimed {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let PatKind::Binding(_, leaked_id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && let ExprKind::Call(..) = init.kind
+            && !in_external_macro(cx.sess(), local.span)
+            && fn_def_id(cx, init).is_some_and(|def_id| match_def_path(cx, def_id, &paths::BOX_LEAK))
+            && let Some(block) = get_enclosing_block(cx, local.hir_id)
+        {
+            let _: Option<!> = for_each_expr(block, |e| {
+                if let ExprKind::Call(_, [arg]) = e.kind
+                    && e.span.lo() > local.span.hi()
+                    && path_to_local_id(peel_casts(arg), leaked_id)
+                    && fn_def_id(cx, e).is_some_and(|def_id| match_def_path(cx, def_id, &paths::BOX_FROM_RAW))
+                {
+                    span_lint_and_then(
+                        cx,
+                        LEAKED_BOX_RECLAIMED,
+                        e.span,
+                        "reclaiming a `Box` that was leaked with `Box::leak`",
+                        |diag| {
+                            diag.span_note(init.span, "the box is leaked here");
+                            diag.help("if the memory is meant to be freed, use `Box::into_raw` instead of `Box::leak`");
+                        },
+                    );
+                }
+                ControlFlow::Continue(())
+            });
+        }
+    }
+}
@@ -35,7 +35,7 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for `let _ = sync_lock`. This supports `mutex` and `rwlock` in
-    /// `parking_lot`. For `std` locks see the `rustc` lint
+    /// `parking_lot`, and `mutex` in `futures`. For `std` locks see the `rustc` lint
     /// [`let_underscore_lock`](https://doc.rust-lang.org/nightly/rustc/lints/listing/deny-by-default.html#let-underscore-lock)
     ///
     /// ### Why is this bad?
@@ -131,10 +131,12 @@ declare_clippy_lint! {
 
 declare_lint_pass!(LetUnderscore => [LET_UNDERSCORE_MUST_USE, LET_UNDERSCORE_LOCK, LET_UNDERSCORE_FUTURE, LET_UNDERSCORE_UNTYPED]);
 
-const SYNC_GUARD_PATHS: [&[&str]; 3] = [
+const SYNC_GUARD_PATHS: [&[&str]; 5] = [
     &paths::PARKING_LOT_MUTEX_GUARD,
     &paths::PARKING_LOT_RWLOCK_READ_GUARD,
     &paths::PARKING_LOT_RWLOCK_WRITE_GUARD,
+    &paths::FUTURES_MUTEX_GUARD,
+    &paths::FUTURES_OWNED_MUTEX_GUARD,
 ];
 
 impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
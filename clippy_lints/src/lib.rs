@@ -75,13 +75,16 @@ mod asm_syntax;
 mod assertions_on_constants;
 mod assertions_on_result_states;
 mod async_yields_async;
+mod atomic_check_then_act;
 mod attrs;
 mod await_holding_invalid;
+mod blocking_op_in_async;
 mod blocks_in_if_conditions;
 mod bool_assert_comparison;
 mod bool_to_int_with_if;
 mod booleans;
 mod borrow_deref_ref;
+mod borrowing_closure_callback;
 mod box_default;
 mod cargo;
 mod casts;
@@ -94,6 +97,10 @@ mod copies;
 mod copy_iterator;
 mod crate_in_macro_def;
 mod create_dir;
+mod cstr_from_unterminated_ptr;
+mod cstr_used_after_buffer_mutation;
+mod cstring_used_after_into_raw;
+mod dangling_cstring_ptr;
 mod dbg_macro;
 mod default;
 mod default_constructed_unit_structs;
@@ -131,8 +138,11 @@ mod exit;
 mod explicit_write;
 mod extra_unused_type_parameters;
 mod fallible_impl_from;
+mod ffi_output_niche_field;
+mod ffi_unsafe_param_type;
 mod float_literal;
 mod floating_point_arithmetic;
+mod forget_owning_resource;
 mod format;
 mod format_args;
 mod format_impl;
@@ -140,13 +150,19 @@ mod format_push_string;
 mod formatting;
 mod four_forward_slashes;
 mod from_over_into;
+mod from_raw_parts_outlives_owner;
+mod from_raw_parts_unbounded_lifetime;
 mod from_raw_with_void_ptr;
 mod from_str_radix_10;
+mod fs_toctou;
 mod functions;
 mod future_not_send;
+mod hardcoded_address_deref;
 mod if_let_mutex;
 mod if_not_else;
 mod if_then_some_else_none;
+mod ignored_libc_return;
+mod ignored_read_length;
 mod ignored_unit_patterns;
 mod implicit_hasher;
 mod implicit_return;
@@ -161,6 +177,7 @@ mod inherent_impl;
 mod inherent_to_string;
 mod init_numbered_fields;
 mod inline_fn_without_body;
+mod insecure_crypto;
 mod instant_subtraction;
 mod int_plus_one;
 mod invalid_upcast_comparisons;
@@ -173,6 +190,7 @@ mod large_futures;
 mod large_include_file;
 mod large_stack_arrays;
 mod large_stack_frames;
+mod leaked_box_reclaimed;
 mod len_zero;
 mod let_if_seq;
 mod let_underscore;
@@ -207,12 +225,15 @@ mod min_ident_chars;
 mod minmax;
 mod misc;
 mod misc_early;
+mod mismatched_dealloc_layout;
+mod mismatched_resource_release;
 mod mismatching_type_param_order;
 mod missing_assert_message;
 mod missing_asserts_for_indexing;
 mod missing_const_for_fn;
 mod missing_doc;
 mod missing_enforced_import_rename;
+mod missing_errno_check;
 mod missing_fields_in_debug;
 mod missing_inline;
 mod missing_trait_methods;
@@ -222,6 +243,7 @@ mod multi_assignments;
 mod multiple_unsafe_ops_per_block;
 mod mut_key;
 mod mut_mut;
+mod mut_ref_to_immutable_static;
 mod mut_reference;
 mod mutable_debug_assertion;
 mod mutex_atomic;
@@ -245,16 +267,20 @@ mod no_effect;
 mod no_mangle_with_rust_abi;
 mod non_canonical_impls;
 mod non_copy_const;
+mod non_crypto_rng_for_secret;
 mod non_expressive_names;
 mod non_octal_unix_permissions;
 mod non_send_fields_in_send_ty;
 mod nonstandard_macro_braces;
 mod octal_escapes;
+mod offset_from_other_container;
 mod only_used_in_recursion;
 mod operators;
 mod option_env_unwrap;
 mod option_if_let_else;
 mod overflow_check_conditional;
+mod packed_field_aligned_access;
+mod panic_in_drop;
 mod panic_in_result_fn;
 mod panic_unimplemented;
 mod partial_pub_fields;
@@ -265,11 +291,16 @@ mod pattern_type_mismatch;
 mod permissions_set_readonly_false;
 mod precedence;
 mod ptr;
+mod ptr_copy_count_units;
+mod ptr_offset_by_other_len;
+mod ptr_offset_out_of_bounds;
 mod ptr_offset_with_cast;
 mod pub_use;
 mod question_mark;
 mod question_mark_used;
+mod racy_env_mutation;
 mod ranges;
+mod raw_alloc_without_null_check;
 mod raw_strings;
 mod rc_clone_in_vec_init;
 mod read_zero_byte_vec;
@@ -283,27 +314,37 @@ mod redundant_pub_crate;
 mod redundant_slicing;
 mod redundant_static_lifetimes;
 mod redundant_type_annotations;
+mod ref_from_unchecked_ptr;
+mod ref_in_foreign_fn;
 mod ref_option_ref;
 mod ref_patterns;
 mod reference;
 mod regex;
 mod reserve_after_initialization;
 mod return_self_not_must_use;
+mod returned_closure_local_ptr;
+mod returned_ptr_to_local;
 mod returns;
+mod rust_layout_byte_copy;
 mod same_name_method;
 mod self_named_constructors;
 mod semicolon_block;
 mod semicolon_if_nothing_returned;
 mod serde_api;
 mod shadow;
+mod signed_len_to_ffi;
 mod significant_drop_tightening;
 mod single_call_fn;
 mod single_char_lifetime_names;
 mod single_component_path_imports;
 mod single_range_in_vec_init;
 mod size_of_in_element_count;
+mod size_of_pointer_in_byte_count;
 mod size_of_ref;
+mod sleep_with_untrusted_duration;
+mod slice_from_raw_parts_untrusted_len;
 mod slow_vector_initialization;
+mod stack_ptr_escapes_via_call;
 mod std_instead_of_core;
 mod strings;
 mod strlen_on_c_strings;
@@ -315,15 +356,19 @@ mod swap;
 mod swap_ptr_to_ref;
 mod tabs_in_doc_comments;
 mod temporary_assignment;
+mod temporary_ptr_escapes_via_call;
 mod tests_outside_test_module;
 mod to_digit_is_some;
 mod trailing_empty_array;
 mod trait_bounds;
 mod transmute;
+mod transposed_ffi_args;
 mod tuple_array_conversions;
 mod types;
+mod unchecked_indexing;
 mod undocumented_unsafe_blocks;
 mod unicode;
+mod uninit_alloc_field_read;
 mod uninit_vec;
 mod unit_return_expecting_ord;
 mod unit_types;
@@ -335,15 +380,18 @@ mod unnecessary_struct_initialization;
 mod unnecessary_wraps;
 mod unnested_or_patterns;
 mod unsafe_removed_from_name;
+mod unterminated_vec_as_c_string;
 mod unused_async;
 mod unused_io_amount;
 mod unused_peekable;
 mod unused_rounding;
 mod unused_self;
 mod unused_unit;
+mod unwind_across_ffi;
 mod unwrap;
 mod unwrap_in_result;
 mod upper_case_acronyms;
+mod use_of_system_call;
 mod use_self;
 mod useless_conversion;
 mod vec;
@@ -353,6 +401,7 @@ mod wildcard_imports;
 mod write;
 mod zero_div_zero;
 mod zero_sized_map_values;
+mod zst_box_ptr_across_ffi;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 use crate::utils::conf::metadata::get_configuration_metadata;
@@ -1102,6 +1151,142 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::<reserve_after_initialization::ReserveAfterInitialization>::default());
     store.register_late_pass(|_| Box::new(implied_bounds_in_impls::ImpliedBoundsInImpls));
     store.register_late_pass(|_| Box::new(missing_asserts_for_indexing::MissingAssertsForIndexing));
+    store.register_late_pass(|_| Box::new(cstr_from_unterminated_ptr::CStrFromUnterminatedPtr));
+    store.register_late_pass(|_| Box::new(rust_layout_byte_copy::RustLayoutByteCopy));
+    let retaining_functions = conf.retaining_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(stack_ptr_escapes_via_call::StackPtrEscapesViaCall::new(
+            retaining_functions.clone(),
+        ))
+    });
+    let ignored_libc_return_functions = conf.ignored_libc_return_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(ignored_libc_return::IgnoredLibcReturn::new(
+            ignored_libc_return_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(mismatched_dealloc_layout::MismatchedDeallocLayout));
+    let unchecked_indexing_crate_wide = conf.unchecked_indexing_crate_wide;
+    store.register_late_pass(move |_| {
+        Box::new(unchecked_indexing::UncheckedIndexing::new(
+            unchecked_indexing_crate_wide,
+        ))
+    });
+    let insecure_crypto_paths = conf.insecure_crypto_paths.clone();
+    store.register_late_pass(move |_| Box::new(insecure_crypto::InsecureCrypto::new(insecure_crypto_paths.clone())));
+    store.register_late_pass(|_| Box::new(zst_box_ptr_across_ffi::ZstBoxPtrAcrossFfi));
+    let non_crypto_rng_paths = conf.non_crypto_rng_paths.clone();
+    let secret_name_patterns = conf.secret_name_patterns.clone();
+    store.register_late_pass(move |_| {
+        Box::new(non_crypto_rng_for_secret::NonCryptoRngForSecret::new(
+            non_crypto_rng_paths.clone(),
+            secret_name_patterns.clone(),
+        ))
+    });
+    let fs_toctou_check_functions = conf.fs_toctou_check_functions.clone();
+    let fs_toctou_use_functions = conf.fs_toctou_use_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(fs_toctou::FsToctou::new(
+            fs_toctou_check_functions.clone(),
+            fs_toctou_use_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(ref_in_foreign_fn::RefInForeignFn));
+    store.register_late_pass(|_| Box::new(panic_in_drop::PanicInDrop));
+    store.register_late_pass(|_| Box::new(unwind_across_ffi::UnwindAcrossFfi));
+    let owning_resource_types = conf.owning_resource_types.clone();
+    store.register_late_pass(move |_| {
+        Box::new(forget_owning_resource::ForgetOwningResource::new(owning_resource_types.clone()))
+    });
+    store.register_late_pass(|_| Box::new(signed_len_to_ffi::SignedLenToFfi));
+    store.register_late_pass(|_| Box::new(atomic_check_then_act::AtomicCheckThenAct));
+    store.register_late_pass(|_| Box::new(cstring_used_after_into_raw::CStringUsedAfterIntoRaw));
+    let env_mutating_functions = conf.env_mutating_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(racy_env_mutation::RacyEnvMutation::new(
+            env_mutating_functions.clone(),
+        ))
+    });
+    let shell_exec_functions = conf.shell_exec_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(use_of_system_call::UseOfSystemCall::new(
+            shell_exec_functions.clone(),
+        ))
+    });
+    let allow_io_blocking_ops = conf.allow_io_blocking_ops;
+    store.register_late_pass(move |_| Box::new(blocking_op_in_async::BlockingOpInAsync::new(allow_io_blocking_ops)));
+    store.register_late_pass(|_| Box::new(ptr_offset_by_other_len::PtrOffsetByOtherLen));
+    store.register_late_pass(|_| Box::new(mut_ref_to_immutable_static::MutRefToImmutableStatic));
+    store.register_late_pass(|_| Box::new(packed_field_aligned_access::PackedFieldAlignedAccess));
+    let resource_function_pairs = conf.resource_function_pairs.clone();
+    store.register_late_pass(move |_| {
+        Box::new(mismatched_resource_release::MismatchedResourceRelease::new(
+            resource_function_pairs.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(size_of_pointer_in_byte_count::SizeOfPointerInByteCount));
+    store.register_late_pass(|_| Box::new(ptr_copy_count_units::PtrCopyCountUnits));
+    store.register_late_pass(|_| Box::new(from_raw_parts_unbounded_lifetime::FromRawPartsUnboundedLifetime));
+    store.register_late_pass(|_| Box::new(leaked_box_reclaimed::LeakedBoxReclaimed));
+    let ffi_memory_functions = conf.ffi_memory_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(transposed_ffi_args::TransposedFfiArgs::new(
+            ffi_memory_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(returned_ptr_to_local::ReturnedPtrToLocal));
+    store.register_late_pass(|_| Box::new(dangling_cstring_ptr::DanglingCStringPtr));
+    let ffi_safe_types = conf.ffi_safe_types.clone();
+    store.register_late_pass(move |_| {
+        Box::new(ffi_unsafe_param_type::FfiUnsafeParamType::new(
+            ffi_safe_types.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(slice_from_raw_parts_untrusted_len::SliceFromRawPartsUntrustedLen));
+    store.register_late_pass(|_| Box::<ffi_output_niche_field::FfiOutputNicheField>::default());
+    let mmio_address_ranges = conf.mmio_address_ranges.clone();
+    store.register_late_pass(move |_| {
+        Box::new(hardcoded_address_deref::HardcodedAddressDeref::new(mmio_address_ranges.clone()))
+    });
+    store.register_late_pass(|_| Box::new(ref_from_unchecked_ptr::RefFromUncheckedPtr));
+    store.register_late_pass(|_| Box::new(raw_alloc_without_null_check::RawAllocWithoutNullCheck));
+    store.register_late_pass(|_| Box::new(ptr_offset_out_of_bounds::PtrOffsetOutOfBounds));
+    store.register_late_pass(|_| Box::new(uninit_alloc_field_read::UninitAllocFieldRead));
+    store.register_late_pass(|_| Box::new(from_raw_parts_outlives_owner::FromRawPartsOutlivesOwner));
+    let retaining_functions = conf.retaining_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(borrowing_closure_callback::BorrowingClosureCallback::new(
+            retaining_functions.clone(),
+        ))
+    });
+    let retaining_functions = conf.retaining_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(temporary_ptr_escapes_via_call::TemporaryPtrEscapesViaCall::new(
+            retaining_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(unterminated_vec_as_c_string::UnterminatedVecAsCString));
+    store.register_late_pass(|_| Box::new(cstr_used_after_buffer_mutation::CStrUsedAfterBufferMutation));
+    store.register_late_pass(|_| Box::new(offset_from_other_container::OffsetFromOtherContainer));
+    store.register_late_pass(|_| Box::new(returned_closure_local_ptr::ReturnedClosureLocalPtr));
+    let read_into_buffer_functions = conf.read_into_buffer_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(ignored_read_length::IgnoredReadLength::new(
+            read_into_buffer_functions.clone(),
+        ))
+    });
+    let errno_setting_functions = conf.errno_setting_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(missing_errno_check::MissingErrnoCheck::new(
+            errno_setting_functions.clone(),
+        ))
+    });
+    let sleep_functions = conf.sleep_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(sleep_with_untrusted_duration::SleepWithUntrustedDuration::new(
+            sleep_functions.clone(),
+        ))
+    });
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -0,0 +1,33 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span, Symbol};
+
+use super::BLOCKING_CALL_WITHOUT_TIMEOUT;
+
+/// Blocking methods along with the type they are defined on and their bounded counterpart.
+const BOUNDED_ALTERNATIVES: [(Symbol, &str, &str); 4] = [
+    (sym::Receiver, "recv", "recv_timeout"),
+    (sym::Mutex, "lock", "try_lock"),
+    (sym::RwLock, "read", "try_read"),
+    (sym::RwLock, "write", "try_write"),
+];
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, name: &str, span: Span) {
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    if let Some((_, _, alternative)) = BOUNDED_ALTERNATIVES
+        .iter()
+        .find(|(ty, method, _)| *method == name && is_type_diagnostic_item(cx, recv_ty, *ty))
+        && !expr.span.from_expansion()
+    {
+        span_lint_and_help(
+            cx,
+            BLOCKING_CALL_WITHOUT_TIMEOUT,
+            span,
+            &format!("calling `{name}` may block indefinitely"),
+            None,
+            &format!("consider using `{alternative}` instead"),
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod bind_instead_of_map;
+mod blocking_call_without_timeout;
 mod bytecount;
 mod bytes_count_to_len;
 mod bytes_nth;
@@ -3563,6 +3564,36 @@ declare_clippy_lint! {
     "calls to `.take()` or `.skip()` that are out of bounds"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to blocking methods that have a bounded counterpart, such as
+    /// `Receiver::recv` (instead of `recv_timeout`) or `Mutex::lock` (instead of `try_lock`).
+    ///
+    /// ### Why is this bad?
+    /// The call blocks until the channel receives a message or the lock is released, which may
+    /// never happen. Code that has to stay responsive should bound the wait instead.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::mpsc::channel;
+    /// let (tx, rx) = channel::<u32>();
+    /// # tx.send(1).unwrap();
+    /// let value = rx.recv();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::mpsc::channel;
+    /// # use std::time::Duration;
+    /// let (tx, rx) = channel::<u32>();
+    /// # tx.send(1).unwrap();
+    /// let value = rx.recv_timeout(Duration::from_secs(1));
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub BLOCKING_CALL_WITHOUT_TIMEOUT,
+    restriction,
+    "calling a blocking method that has a bounded counterpart"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
@@ -3703,6 +3734,7 @@ impl_lint_pass!(Methods => [
     FILTER_MAP_BOOL_THEN,
     READONLY_WRITE_LOCK,
     ITER_OUT_OF_BOUNDS,
+    BLOCKING_CALL_WITHOUT_TIMEOUT,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4072,6 +4104,7 @@ impl Methods {
                 },
                 ("lock", []) => {
                     mut_mutex_lock::check(cx, expr, recv, span);
+                    blocking_call_without_timeout::check(cx, expr, recv, name, span);
                 },
                 (name @ ("map" | "map_err"), [m_arg]) => {
                     if name == "map" {
@@ -4136,6 +4169,7 @@ impl Methods {
                 ("push", [arg]) => {
                     path_buf_push_overwrite::check(cx, expr, arg);
                 },
+                ("read" | "recv", []) => blocking_call_without_timeout::check(cx, expr, recv, name, span),
                 ("read_to_end", [_]) => {
                     verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_END_MSG);
                 },
@@ -4289,6 +4323,7 @@ impl Methods {
                 },
                 ("write", []) => {
                     readonly_write_lock::check(cx, expr, recv);
+                    blocking_call_without_timeout::check(cx, expr, recv, name, span);
                 }
                 ("zip", [arg]) => {
                     if let ExprKind::MethodCall(name, iter_recv, [], _) = recv.kind
@@ -0,0 +1,114 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{expr_or_init, fn_def_id, match_def_path, paths, peel_casts};
+use rustc_hir::{Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `std::alloc::dealloc` calls whose `Layout` has a different size or alignment
+    /// than the `Layout` the pointer was allocated with, when both are known in the same
+    /// function.
+    ///
+    /// ### Why is this bad?
+    /// `dealloc` must be called with the same layout that was used to allocate the memory.
+    /// Anything else is undefined behavior.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::alloc::{alloc, dealloc, Layout};
+    /// unsafe {
+    ///     let ptr = alloc(Layout::new::<u64>());
+    ///     dealloc(ptr, Layout::new::<u32>());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::alloc::{alloc, dealloc, Layout};
+    /// unsafe {
+    ///     let ptr = alloc(Layout::new::<u64>());
+    ///     dealloc(ptr, Layout::new::<u64>());
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub MISMATCHED_DEALLOC_LAYOUT,
+    suspicious,
+    "deallocating with a different `Layout` than the memory was allocated with"
+}
+declare_lint_pass!(MismatchedDeallocLayout => [MISMATCHED_DEALLOC_LAYOUT]);
+
+impl<'tcx> LateLintPass<'tcx> for MismatchedDeallocLayout {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, [ptr, dealloc_layout]) = expr.kind
+            && !expr.span.from_expansion()
+            && fn_def_id(cx, expr).is_some_and(|def_id| match_def_path(cx, def_id, &paths::ALLOC_DEALLOC))
+            && let alloc = peel_casts(expr_or_init(cx, peel_casts(ptr)))
+            && let ExprKind::Call(_, [alloc_layout]) = alloc.kind
+            && fn_def_id(cx, alloc).is_some_and(|def_id| {
+                match_def_path(cx, def_id, &paths::ALLOC_ALLOC) || match_def_path(cx, def_id, &paths::ALLOC_ALLOC_ZEROED)
+            })
+            && let Some((alloc_size, alloc_align)) = eval_layout(cx, alloc_layout)
+            && let Some((dealloc_size, dealloc_align)) = eval_layout(cx, dealloc_layout)
+            && (alloc_size, alloc_align) != (dealloc_size, dealloc_align)
+        {
+            span_lint_and_note(
+                cx,
+                MISMATCHED_DEALLOC_LAYOUT,
+                dealloc_layout.span,
+                &format!(
+                    "deallocating with a layout of size {dealloc_size} and alignment {dealloc_align}, \
+                    which differs from the layout used to allocate"
+                ),
+                Some(alloc_layout.span),
+                &format!("the memory was allocated with size {alloc_size} and alignment {alloc_align} here"),
+            );
+        }
+    }
+}
+
+/// Evaluates a `Layout` constructed with `Layout::new`, `Layout::array` or
+/// `Layout::from_size_align` (optionally unwrapped) to its size and alignment.
+fn eval_layout<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<(u128, u128)> {
+    let mut expr = expr_or_init(cx, expr);
+    if let ExprKind::MethodCall(method, receiver, _, _) = expr.kind
+        && (method.ident.name == sym::unwrap || method.ident.name == sym::expect)
+    {
+        expr = receiver;
+    }
+    let ExprKind::Call(func, args) = expr.kind else {
+        return None;
+    };
+    let def_id = fn_def_id(cx, expr)?;
+    let impl_id = cx.tcx.impl_of_method(def_id)?;
+    if !is_type_lang_item(
+        cx,
+        cx.tcx.type_of(impl_id).instantiate_identity(),
+        LangItem::AllocLayout,
+    ) {
+        return None;
+    }
+    let eval_int = |e| match constant(cx, cx.typeck_results(), e) {
+        Some(Constant::Int(n)) => Some(n),
+        _ => None,
+    };
+    match (cx.tcx.item_name(def_id).as_str(), args) {
+        ("new", []) | ("array", [_]) => {
+            let ty = cx.typeck_results().node_args(func.hir_id).types().next()?;
+            let layout = cx.layout_of(ty).ok()?;
+            let count = match args {
+                [len] => eval_int(len)?,
+                _ => 1,
+            };
+            Some((
+                u128::from(layout.size.bytes()).checked_mul(count)?,
+                u128::from(layout.align.abi.bytes()),
+            ))
+        },
+        ("from_size_align" | "from_size_align_unchecked", [size, align]) => Some((eval_int(size)?, eval_int(align)?)),
+        _ => None,
+    }
+}
@@ -0,0 +1,160 @@
+use crate::utils::conf::ResourcePair;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::hir_utils::SpanlessEq;
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{def_path_def_ids, expr_or_init, fn_def_id, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Body, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions releasing a resource, such as `pthread_mutex_destroy`, being called
+    /// on a different handle than the one the matching acquiring function, such as
+    /// `pthread_mutex_init`, was called on earlier in the same function.
+    ///
+    /// The pairs of functions and the position of their handle argument are configured with the
+    /// `resource-function-pairs` option. By default, the `init`/`destroy` functions of `libc` for
+    /// mutexes, read-write locks, condition variables and semaphores are checked.
+    ///
+    /// ### Why is this bad?
+    /// The handle that was initialized is never released, leaking it, while the released handle
+    /// was possibly never initialized, which is usually undefined behavior.
+    ///
+    /// ### Known problems
+    /// Handles are compared syntactically, so the same handle reached through two different
+    /// expressions is linted.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// libc::pthread_mutex_init(&mut first, ptr::null());
+    /// // ...
+    /// libc::pthread_mutex_destroy(&mut second);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// libc::pthread_mutex_init(&mut first, ptr::null());
+    /// // ...
+    /// libc::pthread_mutex_destroy(&mut first);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub MISMATCHED_RESOURCE_RELEASE,
+    suspicious,
+    "releasing a different handle than the one that was acquired"
+}
+
+/// A configured pair of functions, with the handle positions resolved.
+#[derive(Clone, Debug)]
+struct ResolvedPair {
+    acquire: Vec<DefId>,
+    release: Vec<DefId>,
+    acquire_arg: usize,
+    release_arg: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct MismatchedResourceRelease {
+    pairs: Vec<ResourcePair>,
+    resolved: Vec<ResolvedPair>,
+}
+
+impl MismatchedResourceRelease {
+    pub fn new(pairs: Vec<ResourcePair>) -> Self {
+        Self {
+            pairs,
+            resolved: Vec::new(),
+        }
+    }
+}
+
+impl_lint_pass!(MismatchedResourceRelease => [MISMATCHED_RESOURCE_RELEASE]);
+
+impl<'tcx> LateLintPass<'tcx> for MismatchedResourceRelease {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        let resolve = |path: &str| def_path_def_ids(cx, &path.split("::").collect::<Vec<_>>()).collect::<Vec<_>>();
+        self.resolved = self
+            .pairs
+            .iter()
+            .map(|pair| ResolvedPair {
+                acquire: resolve(&pair.acquire),
+                release: resolve(&pair.release),
+                acquire_arg: pair.acquire_arg,
+                release_arg: pair.release_arg,
+            })
+            .filter(|pair| !pair.acquire.is_empty() && !pair.release.is_empty())
+            .collect();
+    }
+
+    fn check_body(&mut self, cx: &LateContext<'tcx>, body: &'tcx Body<'_>) {
+        if self.resolved.is_empty() {
+            return;
+        }
+        // The handles acquired so far, by pair
+        let mut acquired: Vec<Vec<&Expr<'_>>> = vec![Vec::new(); self.resolved.len()];
+        let _: Option<!> = for_each_expr(body.value, |e| {
+            if let ExprKind::Call(_, args) = e.kind
+                && !in_external_macro(cx.sess(), e.span)
+                && let Some(def_id) = fn_def_id(cx, e)
+            {
+                for (pair, handles) in self.resolved.iter().zip(&mut acquired) {
+                    if pair.acquire.contains(&def_id)
+                        && let Some(handle) = args.get(pair.acquire_arg)
+                    {
+                        handles.push(handle);
+                    } else if pair.release.contains(&def_id)
+                        && let Some(handle) = args.get(pair.release_arg)
+                        && let Some(&last) = handles.last()
+                        && !handles.iter().any(|acquired| same_handle(cx, acquired, handle))
+                    {
+                        span_lint_and_then(
+                            cx,
+                            MISMATCHED_RESOURCE_RELEASE,
+                            handle.span,
+                            &format!(
+                                "releasing `{}` with `{}`, but it isn't the handle that was acquired",
+                                snippet(cx, handle.span, ".."),
+                                cx.tcx.item_name(def_id),
+                            ),
+                            |diag| {
+                                diag.span_note(last.span, "the handle is acquired here");
+                            },
+                        );
+                    }
+                }
+            }
+            ControlFlow::Continue(())
+        });
+    }
+}
+
+fn same_handle(cx: &LateContext<'_>, a: &Expr<'_>, b: &Expr<'_>) -> bool {
+    SpanlessEq::new(cx).eq_expr(peel_handle(cx, a), peel_handle(cx, b))
+}
+
+/// Peels the borrows, casts and pointer conversions around a handle, e.g. `h` in
+/// `ptr::addr_of_mut!(h).cast()`, following the local bindings of raw pointers.
+fn peel_handle<'a>(cx: &LateContext<'_>, mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    loop {
+        expr = peel_casts(expr);
+        if cx.typeck_results().expr_ty(expr).is_unsafe_ptr() {
+            let init = expr_or_init(cx, expr);
+            if init.hir_id != expr.hir_id {
+                expr = init;
+                continue;
+            }
+        }
+        match expr.kind {
+            ExprKind::AddrOf(_, _, inner) => expr = inner,
+            ExprKind::MethodCall(method, receiver, [], _)
+                if matches!(method.ident.name.as_str(), "as_ptr" | "as_mut_ptr" | "cast" | "cast_mut") =>
+            {
+                expr = receiver;
+            },
+            _ => return expr,
+        }
+    }
+}
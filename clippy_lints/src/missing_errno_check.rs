@@ -0,0 +1,226 @@
+use crate::utils::conf::ErrnoSettingFunction;
+use crate::utils::fn_set::FnSet;
+use clippy_utils::consts::{constant_full_int, FullInt};
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{
+    fn_def_id, following_exprs, is_path_diagnostic_item, path_to_local_id, peel_blocks_and_casts, peel_casts,
+};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the return value of a C function reporting its errors through `errno`, such as
+    /// `open` or `fopen`, being stored in a binding but not compared to the value the function
+    /// returns on failure before the next call to a foreign function.
+    ///
+    /// The functions are configured with the `errno-setting-functions` option, along with their
+    /// failure value. By default, it is null for functions returning a pointer and `-1` otherwise.
+    /// Besides the configured paths, `extern` declarations with the same name are checked as well.
+    ///
+    /// ### Why is this bad?
+    /// The value of `errno` is only meaningful right after a call that failed, and any other call
+    /// to a foreign function may overwrite it. If the failure isn't detected first, the error is
+    /// either lost or misreported.
+    ///
+    /// ### Known problems
+    /// Only the statements following the binding in the same block are checked, so a value moved
+    /// to another binding or passed to a helper doing the check is still linted.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let fd = unsafe { libc::open(path.as_ptr(), libc::O_RDONLY) };
+    /// let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let fd = unsafe { libc::open(path.as_ptr(), libc::O_RDONLY) };
+    /// if fd == -1 {
+    ///     return Err(std::io::Error::last_os_error());
+    /// }
+    /// let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub MISSING_ERRNO_CHECK,
+    pedantic,
+    "not comparing the result of a C function setting `errno` to its failure value"
+}
+
+/// The value returned by a function setting `errno` when it fails.
+#[derive(Clone, Copy)]
+enum Failure {
+    Null,
+    Int(i64),
+}
+
+/// What is found first when looking for a check of the value returned by a function setting
+/// `errno`.
+enum ErrnoUse {
+    /// The value is compared to the failure value, or handed over to the caller.
+    Checked,
+    /// A call which may overwrite `errno`.
+    Overwritten(Span),
+}
+
+#[derive(Clone, Debug)]
+pub struct MissingErrnoCheck {
+    errno_functions: Vec<ErrnoSettingFunction>,
+    errno_fns: FnSet,
+    failure_values: FxHashMap<Symbol, i64>,
+}
+
+impl MissingErrnoCheck {
+    pub fn new(errno_functions: Vec<ErrnoSettingFunction>) -> Self {
+        Self {
+            errno_functions,
+            errno_fns: FnSet::default(),
+            failure_values: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the name of the function setting `errno` called in `expr` and the value it
+    /// returns on failure, looking through `unsafe` blocks and casts.
+    fn errno_call(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(Symbol, Failure)> {
+        let expr = peel_blocks_and_casts(expr);
+        if let ExprKind::Call(..) = expr.kind
+            && self.errno_fns.contains_callee(cx, expr)
+            && let Some(def_id) = fn_def_id(cx, expr)
+        {
+            let name = cx.tcx.item_name(def_id);
+            let failure = if cx.typeck_results().expr_ty(expr).is_unsafe_ptr() {
+                Failure::Null
+            } else {
+                Failure::Int(self.failure_values.get(&name).copied().unwrap_or(-1))
+            };
+            Some((name, failure))
+        } else {
+            None
+        }
+    }
+
+    fn check_errno_local(&self, cx: &LateContext<'_>, block: &Block<'_>, i: usize) {
+        if let StmtKind::Local(local) = block.stmts[i].kind
+            && let PatKind::Binding(_, id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && !init.span.from_expansion()
+            && let Some((name, failure)) = self.errno_call(cx, init)
+        {
+            let mut first_use = None;
+            for e in following_exprs(block, i).chain(block.expr) {
+                first_use = for_each_expr(e, |e| {
+                    if is_failure_check(cx, e, id, failure) {
+                        ControlFlow::Break(ErrnoUse::Checked)
+                    } else if let ExprKind::Ret(Some(ret)) = e.kind
+                        && path_to_local_id(peel_casts(ret), id)
+                    {
+                        ControlFlow::Break(ErrnoUse::Checked)
+                    } else if let ExprKind::Call(..) | ExprKind::MethodCall(..) = e.kind
+                        && (fn_def_id(cx, e).is_some_and(|def_id| cx.tcx.is_foreign_item(def_id))
+                            || self.errno_fns.contains_callee(cx, e))
+                    {
+                        ControlFlow::Break(ErrnoUse::Overwritten(e.span))
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                });
+                if first_use.is_some() {
+                    break;
+                }
+            }
+            if first_use.is_none() && block.expr.is_some_and(|e| path_to_local_id(peel_casts(e), id)) {
+                first_use = Some(ErrnoUse::Checked);
+            }
+            let failure = match failure {
+                Failure::Null => "null".to_owned(),
+                Failure::Int(value) => format!("`{value}`"),
+            };
+            match first_use {
+                Some(ErrnoUse::Checked) => {},
+                Some(ErrnoUse::Overwritten(span)) => span_lint_and_then(
+                    cx,
+                    MISSING_ERRNO_CHECK,
+                    init.span,
+                    &format!("the value returned by `{name}` isn't compared to {failure} before `errno` may change"),
+                    |diag| {
+                        diag.span_note(span, "this call may overwrite `errno`");
+                        diag.help(format!("compare it to {failure} and read `errno` right away"));
+                    },
+                ),
+                None => span_lint_and_help(
+                    cx,
+                    MISSING_ERRNO_CHECK,
+                    init.span,
+                    &format!("the value returned by `{name}` is never compared to {failure}"),
+                    None,
+                    &format!("compare it to {failure} and read `errno` right away"),
+                ),
+            }
+        }
+    }
+}
+
+impl_lint_pass!(MissingErrnoCheck => [MISSING_ERRNO_CHECK]);
+
+impl<'tcx> LateLintPass<'tcx> for MissingErrnoCheck {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for function in &self.errno_functions {
+            if let Some(name) = self.errno_fns.insert(cx, function.path())
+                && let Some(failure) = function.failure()
+            {
+                self.failure_values.insert(name, failure);
+            }
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+        for i in 0..block.stmts.len() {
+            self.check_errno_local(cx, block, i);
+        }
+    }
+}
+
+/// Checks if `expr` compares the local `id` to the `failure` value, or matches on it.
+fn is_failure_check(cx: &LateContext<'_>, expr: &Expr<'_>, id: HirId, failure: Failure) -> bool {
+    match expr.kind {
+        ExprKind::Binary(op, lhs, rhs) if op.node.is_comparison() => {
+            let other = if path_to_local_id(peel_casts(lhs), id) {
+                rhs
+            } else if path_to_local_id(peel_casts(rhs), id) {
+                lhs
+            } else {
+                return false;
+            };
+            match failure {
+                Failure::Null => {
+                    if let ExprKind::Call(func, []) = other.kind {
+                        is_path_diagnostic_item(cx, func, sym::ptr_null)
+                            || is_path_diagnostic_item(cx, func, sym::ptr_null_mut)
+                    } else {
+                        false
+                    }
+                },
+                Failure::Int(value) => {
+                    let other = constant_full_int(cx, cx.typeck_results(), other);
+                    if let BinOpKind::Eq | BinOpKind::Ne = op.node {
+                        other == Some(FullInt::S(value.into()))
+                    } else {
+                        // `n < 0` checks for a failure value of `-1` as well
+                        other.is_some()
+                    }
+                },
+            }
+        },
+        ExprKind::MethodCall(method, receiver, [], _) => {
+            matches!(failure, Failure::Null)
+                && method.ident.name.as_str() == "is_null"
+                && path_to_local_id(receiver, id)
+        },
+        ExprKind::Match(scrutinee, ..) => path_to_local_id(peel_casts(scrutinee), id),
+        _ => false,
+    }
+}
@@ -0,0 +1,90 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{expr_or_init, peel_casts};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{BorrowKind, Expr, ExprKind, Mutability, QPath, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for mutable references created from a pointer to an immutable `static`, such as
+    /// `&mut *(&STATIC as *const T as *mut T)`, including through `ptr::addr_of!`, `cast_mut()`
+    /// and local bindings. Mutable references created with `as_mut()` on such a pointer are
+    /// checked as well.
+    ///
+    /// Statics with interior mutability are not linted.
+    ///
+    /// ### Why is this bad?
+    /// Immutable statics are never allowed to change, and the compiler may place them in
+    /// read-only memory. Creating a mutable reference to one is undefined behavior, even
+    /// without writing through it.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// static LIMIT: u32 = 10;
+    /// let limit = unsafe { &mut *(&LIMIT as *const u32 as *mut u32) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::sync::atomic::{AtomicU32, Ordering};
+    /// static LIMIT: AtomicU32 = AtomicU32::new(10);
+    /// LIMIT.store(20, Ordering::Relaxed);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub MUT_REF_TO_IMMUTABLE_STATIC,
+    correctness,
+    "creating a mutable reference to an immutable `static`"
+}
+declare_lint_pass!(MutRefToImmutableStatic => [MUT_REF_TO_IMMUTABLE_STATIC]);
+
+impl<'tcx> LateLintPass<'tcx> for MutRefToImmutableStatic {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let ptr = match expr.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, Mutability::Mut, inner)
+                if let ExprKind::Unary(UnOp::Deref, ptr) = inner.kind =>
+            {
+                ptr
+            },
+            ExprKind::MethodCall(method, ptr, [], _) if method.ident.as_str() == "as_mut" => ptr,
+            _ => return,
+        };
+        if !in_external_macro(cx.sess(), expr.span)
+            && cx.typeck_results().expr_ty(ptr).is_unsafe_ptr()
+            && let Some(static_span) = immutable_static_origin(cx, ptr)
+        {
+            span_lint_and_then(
+                cx,
+                MUT_REF_TO_IMMUTABLE_STATIC,
+                expr.span,
+                "creating a mutable reference to an immutable `static`",
+                |diag| {
+                    diag.span_note(static_span, "the pointer is taken to the `static` here");
+                    diag.help("declare the `static` with a type providing interior mutability, such as an atomic");
+                },
+            );
+        }
+    }
+}
+
+/// If the pointer `expr` points to an immutable `static` without interior mutability, returns the
+/// span where the pointer is taken, following casts and local bindings.
+fn immutable_static_origin(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> Option<Span> {
+    loop {
+        expr = peel_casts(expr_or_init(cx, peel_casts(expr)));
+        match expr.kind {
+            ExprKind::MethodCall(method, receiver, [], _) if matches!(method.ident.as_str(), "cast" | "cast_mut") => {
+                expr = receiver;
+            },
+            ExprKind::AddrOf(_, Mutability::Not, place)
+                if let ExprKind::Path(QPath::Resolved(None, path)) = place.kind
+                    && let Res::Def(DefKind::Static(Mutability::Not), def_id) = path.res =>
+            {
+                let ty = cx.tcx.type_of(def_id).instantiate_identity();
+                return ty.is_freeze(cx.tcx, cx.param_env).then_some(expr.span.source_callsite());
+            },
+            _ => return None,
+        }
+    }
+}
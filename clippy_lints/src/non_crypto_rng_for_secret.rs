@@ -0,0 +1,179 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{def_path_def_ids, fn_def_id, path_to_local};
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Expr, ExprKind, Local, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for random number generators that aren't cryptographically secure being used to
+    /// generate values that look like secrets, i.e. bindings and fields named like `key`,
+    /// `nonce`, `iv`, `token`, `password` or `salt`.
+    ///
+    /// The generators are configured with the `non-crypto-rng-paths` option, and the names with
+    /// the `secret-name-patterns` option. A name matches if one of its underscore-separated words
+    /// is equal to one of the patterns, ignoring case.
+    ///
+    /// ### Why is this bad?
+    /// Secrets must be unpredictable. The output of a userspace generator such as `SmallRng`
+    /// can be predicted from its previous output or its seed.
+    ///
+    /// ### Known problems
+    /// `ThreadRng` is linted too. While it is currently backed by a cryptographically secure
+    /// algorithm, it lives in process memory and isn't reseeded after a `fork`, so the OS
+    /// generator should be preferred for long-lived secrets.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use rand::Rng;
+    /// let mut key = [0u8; 32];
+    /// rand::thread_rng().fill(&mut key);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use rand::Rng;
+    /// let mut key = [0u8; 32];
+    /// rand::rngs::OsRng.fill(&mut key);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub NON_CRYPTO_RNG_FOR_SECRET,
+    restriction,
+    "generating a secret with a random number generator that isn't cryptographically secure"
+}
+
+#[derive(Clone, Debug)]
+pub struct NonCryptoRngForSecret {
+    conf_paths: Vec<String>,
+    secret_names: Vec<String>,
+    def_ids: DefIdSet,
+}
+
+impl NonCryptoRngForSecret {
+    pub fn new(conf_paths: Vec<String>, secret_names: Vec<String>) -> Self {
+        Self {
+            conf_paths,
+            secret_names,
+            def_ids: DefIdSet::default(),
+        }
+    }
+
+    fn is_secret_name(&self, name: Symbol) -> bool {
+        name.as_str()
+            .split('_')
+            .any(|word| self.secret_names.iter().any(|secret| secret.eq_ignore_ascii_case(word)))
+    }
+
+    fn is_rng_ty(&self, ty: Ty<'_>) -> bool {
+        matches!(ty.peel_refs().kind(), ty::Adt(adt, _) if self.def_ids.contains(&adt.did()))
+    }
+
+    /// Checks if `expr` is one of the configured generators, or a call to a configured function
+    /// returning one, e.g. `rand::thread_rng()`.
+    fn is_rng(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+        self.is_rng_ty(cx.typeck_results().expr_ty(expr))
+            || fn_def_id(cx, expr).is_some_and(|def_id| self.def_ids.contains(&def_id))
+    }
+
+    /// Checks if `expr` produces a random value, e.g. `rand::random()` or `rng.gen()`.
+    fn is_random_value(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+        if self.is_rng_ty(cx.typeck_results().expr_ty(expr)) {
+            return false;
+        }
+        match expr.kind {
+            ExprKind::Call(..) => fn_def_id(cx, expr).is_some_and(|def_id| self.def_ids.contains(&def_id)),
+            ExprKind::MethodCall(_, receiver, ..) => self.is_rng(cx, receiver),
+            _ => false,
+        }
+    }
+}
+
+impl_lint_pass!(NonCryptoRngForSecret => [NON_CRYPTO_RNG_FOR_SECRET]);
+
+impl<'tcx> LateLintPass<'tcx> for NonCryptoRngForSecret {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.conf_paths {
+            let segs: Vec<_> = path.split("::").collect();
+            self.def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let Some(init) = local.init
+            && let PatKind::Binding(_, _, ident, _) = local.pat.kind
+            && !local.span.from_expansion()
+            && self.is_secret_name(ident.name)
+            && self.is_random_value(cx, init)
+        {
+            lint(cx, init.span, ident.name);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(method, receiver, [target, ..], _)
+                if matches!(
+                    method.ident.as_str(),
+                    "fill" | "fill_bytes" | "try_fill" | "try_fill_bytes"
+                ) && self.is_rng(cx, receiver) =>
+            {
+                if let Some(name) = target_name(cx, target)
+                    && self.is_secret_name(name)
+                {
+                    lint(cx, expr.span, name);
+                }
+            },
+            ExprKind::Assign(target, value, _) => {
+                if let Some(name) = target_name(cx, target)
+                    && self.is_secret_name(name)
+                    && self.is_random_value(cx, value)
+                {
+                    lint(cx, value.span, name);
+                }
+            },
+            ExprKind::Struct(_, fields, _) => {
+                for field in fields {
+                    if self.is_secret_name(field.ident.name) && self.is_random_value(cx, field.expr) {
+                        lint(cx, field.expr.span, field.ident.name);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Returns the name of the local or field written through `expr`, looking through borrows,
+/// dereferences and indexing, e.g. `key` for `&mut self.key[..16]`.
+fn target_name(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> Option<Symbol> {
+    loop {
+        match expr.kind {
+            ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) | ExprKind::Index(inner, ..) => {
+                expr = inner;
+            },
+            ExprKind::MethodCall(method, receiver, [], _)
+                if matches!(method.ident.as_str(), "as_mut" | "as_mut_slice") =>
+            {
+                expr = receiver;
+            },
+            ExprKind::Field(_, ident) => return Some(ident.name),
+            _ => return path_to_local(expr).map(|id| cx.tcx.hir().name(id)),
+        }
+    }
+}
+
+fn lint(cx: &LateContext<'_>, span: Span, name: Symbol) {
+    span_lint_and_help(
+        cx,
+        NON_CRYPTO_RNG_FOR_SECRET,
+        span,
+        &format!("`{name}` is generated with a random number generator that isn't cryptographically secure"),
+        None,
+        "use `rand::rngs::OsRng` or the `getrandom` crate to generate secrets",
+    );
+}
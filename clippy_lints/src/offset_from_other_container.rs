@@ -0,0 +1,76 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::hir_utils::SpanlessEq;
+use clippy_utils::source::snippet;
+use clippy_utils::{as_ptr_source, peel_hir_expr_refs};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `offset_from` and `byte_offset_from` between pointers taken out of two
+    /// different containers with `as_ptr` or `as_mut_ptr`, e.g.
+    /// `a.as_ptr().offset_from(b.as_ptr())`.
+    ///
+    /// The containers are compared syntactically, ignoring indexing, and the pointers may be bound
+    /// to locals first.
+    ///
+    /// ### Why is this bad?
+    /// Both pointers must be derived from the same allocation, otherwise the call is undefined
+    /// behavior. The distance between two unrelated buffers is meaningless anyway.
+    ///
+    /// ### Known problems
+    /// Two different names for the same container, such as two references to the same `Vec`, are
+    /// linted as well.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let start = a.as_ptr();
+    /// let len = unsafe { end.offset_from(b.as_ptr()) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let start = a.as_ptr();
+    /// let len = unsafe { end.offset_from(start) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub OFFSET_FROM_OTHER_CONTAINER,
+    nursery,
+    "computing the distance between pointers into two different containers"
+}
+declare_lint_pass!(OffsetFromOtherContainer => [OFFSET_FROM_OTHER_CONTAINER]);
+
+impl<'tcx> LateLintPass<'tcx> for OffsetFromOtherContainer {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::MethodCall(method, receiver, [origin], _) = expr.kind
+            && matches!(method.ident.as_str(), "offset_from" | "byte_offset_from")
+            && !expr.span.from_expansion()
+            && cx.typeck_results().expr_ty(receiver).is_unsafe_ptr()
+            && let Some(source) = as_ptr_source(cx, receiver)
+            && let Some(origin_source) = as_ptr_source(cx, origin)
+            && !SpanlessEq::new(cx).eq_expr(container(source), container(origin_source))
+        {
+            span_lint_and_help(
+                cx,
+                OFFSET_FROM_OTHER_CONTAINER,
+                expr.span,
+                &format!(
+                    "computing the distance between pointers into `{}` and `{}`",
+                    snippet(cx, source.span, ".."),
+                    snippet(cx, origin_source.span, ".."),
+                ),
+                None,
+                "only compute the distance between pointers into the same allocation",
+            );
+        }
+    }
+}
+
+/// Peels references and indexing off `expr`, e.g. `a` in `&a[1..]`.
+fn container<'a>(expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    let mut expr = peel_hir_expr_refs(expr).0;
+    while let ExprKind::Index(base, ..) = expr.kind {
+        expr = peel_hir_expr_refs(base).0;
+    }
+    expr
+}
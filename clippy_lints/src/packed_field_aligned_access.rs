@@ -0,0 +1,113 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{expr_or_init, fn_def_id, match_def_path, paths, peel_casts};
+use rustc_hir::{BorrowKind, Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for aligned reads and writes, such as `ptr::read` or `ptr.write(..)`, through a
+    /// pointer taken with `ptr::addr_of!` or `ptr::addr_of_mut!` to a field of a packed struct
+    /// whose type needs a larger alignment than the struct's packing.
+    ///
+    /// ### Why is this bad?
+    /// `addr_of!` is the right way to get a pointer to a packed field, since that pointer may
+    /// be unaligned. But `read` and `write` require an aligned pointer, so using them on it is
+    /// undefined behavior all the same. `read_unaligned` and `write_unaligned` must be used
+    /// instead.
+    ///
+    /// ### Example
+    /// ```rust
+    /// #[repr(C, packed)]
+    /// struct Header {
+    ///     tag: u8,
+    ///     len: u32,
+    /// }
+    ///
+    /// let header = Header { tag: 1, len: 4 };
+    /// let len = unsafe { std::ptr::addr_of!(header.len).read() };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # #[repr(C, packed)]
+    /// # struct Header {
+    /// #     tag: u8,
+    /// #     len: u32,
+    /// # }
+    /// # let header = Header { tag: 1, len: 4 };
+    /// let len = unsafe { std::ptr::addr_of!(header.len).read_unaligned() };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub PACKED_FIELD_ALIGNED_ACCESS,
+    correctness,
+    "reading or writing a packed field through `addr_of!` with an aligned access"
+}
+declare_lint_pass!(PackedFieldAlignedAccess => [PACKED_FIELD_ALIGNED_ACCESS]);
+
+impl<'tcx> LateLintPass<'tcx> for PackedFieldAlignedAccess {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (ptr, access) = match expr.kind {
+            ExprKind::Call(_, [ptr, ..]) => {
+                let Some(def_id) = fn_def_id(cx, expr) else { return };
+                if match_def_path(cx, def_id, &paths::PTR_READ) {
+                    (ptr, "read")
+                } else if cx.tcx.is_diagnostic_item(sym::ptr_write, def_id) {
+                    (ptr, "write")
+                } else {
+                    return;
+                }
+            },
+            ExprKind::MethodCall(method, ptr, _, _)
+                if matches!(method.ident.as_str(), "read" | "write")
+                    && cx.typeck_results().expr_ty(ptr).is_unsafe_ptr() =>
+            {
+                (ptr, method.ident.as_str())
+            },
+            _ => return,
+        };
+        if !in_external_macro(cx.sess(), expr.span)
+            && let Some(origin) = packed_field_origin(cx, ptr)
+        {
+            span_lint_and_then(
+                cx,
+                PACKED_FIELD_ALIGNED_ACCESS,
+                expr.span,
+                &format!("aligned {access} through a pointer to a field of a packed struct"),
+                |diag| {
+                    diag.span_note(origin, "the pointer to the packed field is taken here");
+                    diag.help(format!("use `{access}_unaligned` instead"));
+                },
+            );
+        }
+    }
+}
+
+/// If the pointer `expr` was taken with `addr_of!` to a field of a packed struct which may be
+/// misaligned, returns the span where it was taken, following casts and local bindings.
+fn packed_field_origin(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> Option<Span> {
+    loop {
+        expr = peel_casts(expr_or_init(cx, peel_casts(expr)));
+        match expr.kind {
+            ExprKind::MethodCall(method, receiver, [], _)
+                if matches!(method.ident.as_str(), "cast_mut" | "cast_const") =>
+            {
+                expr = receiver;
+            },
+            ExprKind::AddrOf(BorrowKind::Raw, _, place) if let ExprKind::Field(base, _) = place.kind => {
+                let typeck = cx.typeck_results();
+                if let ty::Adt(adt, _) = typeck.expr_ty_adjusted(base).peel_refs().kind()
+                    && let Some(pack) = adt.repr().pack
+                    && let Ok(layout) = cx.layout_of(typeck.expr_ty(place))
+                    && layout.align.abi > pack
+                {
+                    return Some(expr.span.source_callsite());
+                }
+                return None;
+            },
+            _ => return None,
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::utils::panicking_exprs::panicking_exprs;
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir as hir;
+use rustc_hir::intravisit::FnKind;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `Drop::drop` implementations that may panic, through `panic!`, assertions,
+    /// `unwrap`, `expect` or indexing.
+    ///
+    /// ### Why is this bad?
+    /// Values are also dropped while unwinding from a panic. Panicking again at that point
+    /// aborts the whole process, and unwinding out of a destructor called from foreign code is
+    /// undefined behavior.
+    ///
+    /// ### Known problems
+    /// Functions called from `drop` may panic as well. This is not checked. Closures are only
+    /// checked if they aren't stored in a binding, field or struct, as stored closures may
+    /// never be called, and if they aren't passed to `std::panic::catch_unwind`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// struct Log(File);
+    ///
+    /// impl Drop for Log {
+    ///     fn drop(&mut self) {
+    ///         self.0.flush().unwrap();
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// struct Log(File);
+    ///
+    /// impl Drop for Log {
+    ///     fn drop(&mut self) {
+    ///         if let Err(e) = self.0.flush() {
+    ///             eprintln!("failed to flush the log: {e}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub PANIC_IN_DROP,
+    restriction,
+    "`Drop::drop` implementations that may panic"
+}
+
+declare_lint_pass!(PanicInDrop => [PANIC_IN_DROP]);
+
+impl<'tcx> LateLintPass<'tcx> for PanicInDrop {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        fn_kind: FnKind<'tcx>,
+        _: &'tcx hir::FnDecl<'tcx>,
+        body: &'tcx hir::Body<'tcx>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if !matches!(fn_kind, FnKind::Method(..)) || span.from_expansion() {
+            return;
+        }
+        if let Some(impl_id) = cx.tcx.impl_of_method(def_id.to_def_id())
+            && cx.tcx.trait_id_of_impl(impl_id) == cx.tcx.lang_items().drop_trait()
+        {
+            lint_drop_body(cx, span, body);
+        }
+    }
+}
+
+fn lint_drop_body<'tcx>(cx: &LateContext<'tcx>, drop_span: Span, body: &'tcx hir::Body<'tcx>) {
+    let panics = panicking_exprs(cx, body, false);
+    if !panics.is_empty() {
+        span_lint_and_then(cx, PANIC_IN_DROP, drop_span, "`Drop::drop` may panic", move |diag| {
+            diag.span_note(panics, "a panic while unwinding aborts the process");
+            diag.help("consider logging the error instead of panicking");
+        });
+    }
+}
@@ -1,10 +1,13 @@
 //! Checks for usage of  `&Vec[_]` and `&String`.
 
-use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_and_then, span_lint_hir_and_then};
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::{
+    span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then, span_lint_hir_and_then,
+};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::expr_sig;
 use clippy_utils::visitors::contains_unsafe_block;
-use clippy_utils::{get_expr_use_or_unification_node, is_lint_allowed, path_def_id, path_to_local, paths};
+use clippy_utils::{get_expr_use_or_unification_node, is_lint_allowed, is_null_path, path_to_local, paths};
 use hir::LifetimeName;
 use if_chain::if_chain;
 use rustc_errors::{Applicability, MultiSpan};
@@ -321,6 +324,23 @@ fn check_invalid_ptr_usage<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         then {
             for &arg_idx in arg_indices {
                 if let Some(arg) = args.get(arg_idx).filter(|arg| is_null_path(cx, arg)) {
+                    // A dangling pointer is only valid for an empty slice, so don't suggest it
+                    // unless the length is known to be zero.
+                    if let [_, len] = args
+                        && (fun_def_path == paths::SLICE_FROM_RAW_PARTS
+                            || fun_def_path == paths::SLICE_FROM_RAW_PARTS_MUT)
+                        && constant(cx, cx.typeck_results(), len) != Some(Constant::Int(0))
+                    {
+                        span_lint_and_help(
+                            cx,
+                            INVALID_NULL_PTR_USAGE,
+                            arg.span,
+                            "pointer must be non-null",
+                            None,
+                            "a null pointer is never valid for a non-empty slice, pass a pointer to the slice's elements",
+                        );
+                        continue;
+                    }
                     span_lint_and_sugg(
                         cx,
                         INVALID_NULL_PTR_USAGE,
@@ -779,13 +799,3 @@ fn get_ref_lm<'tcx>(ty: &'tcx hir::Ty<'tcx>) -> Option<(&'tcx Lifetime, Mutabili
         None
     }
 }
-
-fn is_null_path(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
-    if let ExprKind::Call(pathexp, []) = expr.kind {
-        path_def_id(cx, pathexp).map_or(false, |id| {
-            matches!(cx.tcx.get_diagnostic_name(id), Some(sym::ptr_null | sym::ptr_null_mut))
-        })
-    } else {
-        false
-    }
-}
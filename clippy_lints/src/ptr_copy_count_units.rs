@@ -0,0 +1,98 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{fn_def_id, get_size_of_ty, match_def_path, paths};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty, TypeAndMut};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `ptr::copy`, `ptr::copy_nonoverlapping` and `ptr::write_bytes`, or the
+    /// equivalent pointer methods, called with a count multiplied by the size of a type other
+    /// than the pointee, when the pointee is larger than a byte.
+    ///
+    /// The case of the size of the pointee itself is covered by `size_of_in_element_count`.
+    ///
+    /// ### Why is this bad?
+    /// These functions take a count of elements of the pointee, not of bytes, `write_bytes`
+    /// included. A count multiplied by a size is most likely a count of bytes, which makes the
+    /// call access several times more memory than intended.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), len * size_of::<u32>());
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), len);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub PTR_COPY_COUNT_UNITS,
+    suspicious,
+    "passing a count of bytes to a pointer copy function counting elements"
+}
+declare_lint_pass!(PtrCopyCountUnits => [PTR_COPY_COUNT_UNITS]);
+
+impl<'tcx> LateLintPass<'tcx> for PtrCopyCountUnits {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !in_external_macro(cx.sess(), expr.span)
+            && let Some((name, pointee_ty, count)) = copy_call(cx, expr)
+            && let Ok(layout) = cx.layout_of(pointee_ty)
+            && layout.size.bytes() > 1
+            && let Some(size_of_ty) = get_size_of_ty(cx, count, false)
+            && size_of_ty != pointee_ty
+        {
+            span_lint_and_then(
+                cx,
+                PTR_COPY_COUNT_UNITS,
+                count.span,
+                &format!("the count passed to `{name}` is multiplied by the size of `{size_of_ty}`"),
+                |diag| {
+                    let action = if name == "write_bytes" { "sets" } else { "copies" };
+                    diag.note(format!(
+                        "`{name}` counts elements of `{pointee_ty}`, it {action} `count * {}` bytes",
+                        layout.size.bytes()
+                    ));
+                    diag.help("pass the number of elements instead of the number of bytes");
+                },
+            );
+        }
+    }
+}
+
+/// Returns the name, pointee type and count argument of a call to a pointer copy function.
+fn copy_call<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<(&'static str, Ty<'tcx>, &'tcx Expr<'tcx>)> {
+    match expr.kind {
+        ExprKind::Call(func, [.., count]) => {
+            let def_id = fn_def_id(cx, expr)?;
+            let name = if match_def_path(cx, def_id, &paths::PTR_COPY) {
+                "copy"
+            } else if match_def_path(cx, def_id, &paths::PTR_COPY_NONOVERLAPPING) {
+                "copy_nonoverlapping"
+            } else if match_def_path(cx, def_id, &paths::PTR_WRITE_BYTES) {
+                "write_bytes"
+            } else {
+                return None;
+            };
+            let pointee_ty = cx.typeck_results().node_args(func.hir_id).types().next()?;
+            Some((name, pointee_ty, count))
+        },
+        ExprKind::MethodCall(method, receiver, [.., count], _) => {
+            let name = match method.ident.name.as_str() {
+                "copy_to" => "copy_to",
+                "copy_from" => "copy_from",
+                "copy_to_nonoverlapping" => "copy_to_nonoverlapping",
+                "copy_from_nonoverlapping" => "copy_from_nonoverlapping",
+                "write_bytes" => "write_bytes",
+                _ => return None,
+            };
+            if let ty::RawPtr(TypeAndMut { ty: pointee_ty, .. }) = cx.typeck_results().expr_ty(receiver).kind() {
+                Some((name, *pointee_ty, count))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
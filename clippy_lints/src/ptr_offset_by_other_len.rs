@@ -0,0 +1,70 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::hir_utils::SpanlessEq;
+use clippy_utils::source::snippet;
+use clippy_utils::{as_ptr_source, peel_casts, peel_hir_expr_refs};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for pointers taken out of one container with `as_ptr` or `as_mut_ptr` being offset
+    /// by the length of another container, e.g. `a.as_ptr().add(b.len())`.
+    ///
+    /// The containers are compared syntactically, and the pointer may be bound to a local first.
+    ///
+    /// ### Why is this bad?
+    /// Unless both containers are known to have the same length, the resulting pointer may be out
+    /// of the bounds of the first one, which is undefined behavior for `add` and `offset`. Mixing up
+    /// two buffers this way is an easy mistake when copying between them.
+    ///
+    /// ### Known problems
+    /// Code that checked or reserved enough room beforehand, such as appending `b` to `a` after
+    /// `a.reserve(b.len())`, is linted as well.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let p = a.as_ptr();
+    /// let end = unsafe { p.add(b.len()) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let p = a.as_ptr();
+    /// let end = unsafe { p.add(a.len()) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub PTR_OFFSET_BY_OTHER_LEN,
+    nursery,
+    "offsetting a pointer into a container by the length of another container"
+}
+declare_lint_pass!(PtrOffsetByOtherLen => [PTR_OFFSET_BY_OTHER_LEN]);
+
+impl<'tcx> LateLintPass<'tcx> for PtrOffsetByOtherLen {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::MethodCall(method, receiver, [offset], _) = expr.kind
+            && matches!(
+                method.ident.as_str(),
+                "add" | "offset" | "wrapping_add" | "wrapping_offset"
+            )
+            && !expr.span.from_expansion()
+            && cx.typeck_results().expr_ty(receiver).is_unsafe_ptr()
+            && let ExprKind::MethodCall(len, other, [], _) = peel_casts(offset).kind
+            && len.ident.as_str() == "len"
+            && let Some(source) = as_ptr_source(cx, receiver)
+            && !SpanlessEq::new(cx).eq_expr(peel_hir_expr_refs(source).0, peel_hir_expr_refs(other).0)
+        {
+            span_lint_and_help(
+                cx,
+                PTR_OFFSET_BY_OTHER_LEN,
+                expr.span,
+                &format!(
+                    "offsetting a pointer into `{}` by the length of `{}`",
+                    snippet(cx, source.span, ".."),
+                    snippet(cx, other.span, ".."),
+                ),
+                None,
+                "make sure the pointer stays in bounds of the container it was taken from",
+            );
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::hir_utils::SpanlessEq;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{expr_or_init, higher, path_to_local, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for raw pointers offset with `add`, `offset`, `wrapping_add` or `wrapping_offset`
+    /// by a non-constant amount, or by a `for` loop variable over a range with a non-constant
+    /// end, which isn't compared to a length beforehand.
+    ///
+    /// An amount is considered bounded if it's the result of `len()` or `capacity()`, possibly
+    /// through `min`, or if it was compared to such a length earlier in the function, e.g. in an
+    /// `assert!`.
+    ///
+    /// ### Why is this bad?
+    /// Offsetting a pointer past the end of its allocation is undefined behavior for `add` and
+    /// `offset`, and dereferencing the result is for all of them. Nothing ties an arbitrary count
+    /// to the size of the buffer the pointer points to.
+    ///
+    /// ### Known problems
+    /// Offsets that are bounded by the caller, as documented in the safety section of an
+    /// `unsafe fn`, are linted as well.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// for i in 0..n {
+    ///     *p.add(i) = 0;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let buf = std::slice::from_raw_parts_mut(p, len);
+    /// for byte in &mut buf[..n] {
+    ///     *byte = 0;
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub PTR_OFFSET_OUT_OF_BOUNDS,
+    restriction,
+    "offsetting a raw pointer by an amount that isn't checked against a length"
+}
+declare_lint_pass!(PtrOffsetOutOfBounds => [PTR_OFFSET_OUT_OF_BOUNDS]);
+
+impl<'tcx> LateLintPass<'tcx> for PtrOffsetOutOfBounds {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::MethodCall(method, receiver, [offset], _) = expr.kind
+            && matches!(method.ident.as_str(), "add" | "offset" | "wrapping_add" | "wrapping_offset")
+            && !in_external_macro(cx.sess(), expr.span)
+            && cx.typeck_results().expr_ty(receiver).is_unsafe_ptr()
+            && let bound = offset_bound(cx, peel_casts(offset))
+            && constant(cx, cx.typeck_results(), bound).is_none()
+            && !is_length(cx, bound)
+            && !is_compared_to_length_before(cx, bound, expr.span)
+        {
+            span_lint_and_help(
+                cx,
+                PTR_OFFSET_OUT_OF_BOUNDS,
+                expr.span,
+                "offsetting a pointer by an amount that isn't checked against the length of the allocation",
+                None,
+                "iterate over a slice of the allocation instead, or check the amount against its length first",
+            );
+        }
+    }
+}
+
+/// If `offset` is the variable of a `for` loop over a range, returns the end of the range,
+/// otherwise returns `offset` itself.
+fn offset_bound<'tcx>(cx: &LateContext<'tcx>, offset: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    if let Some(id) = path_to_local(offset)
+        && let Some(for_loop) = cx.tcx.hir().parent_iter(id).find_map(|(_, node)| match node {
+            Node::Expr(e) => higher::ForLoop::hir(e),
+            _ => None,
+        })
+        && for_loop.pat.hir_id == id
+        && let Some(range) = higher::Range::hir(for_loop.arg)
+        && let Some(end) = range.end
+    {
+        peel_casts(end)
+    } else {
+        offset
+    }
+}
+
+/// Whether `expr` is the result of `len()` or `capacity()`, possibly bound to a local or
+/// limited with `min`.
+fn is_length(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    match peel_casts(expr_or_init(cx, peel_casts(expr))).kind {
+        ExprKind::MethodCall(method, _, [], _) => matches!(method.ident.as_str(), "len" | "capacity"),
+        ExprKind::MethodCall(method, receiver, [arg], _) if method.ident.as_str() == "min" => {
+            is_length(cx, receiver) || is_length(cx, arg)
+        },
+        _ => false,
+    }
+}
+
+/// Whether `bound` is compared to a length somewhere in the enclosing body before `before`.
+fn is_compared_to_length_before(cx: &LateContext<'_>, bound: &Expr<'_>, before: Span) -> bool {
+    let Some(body_id) = cx.enclosing_body else {
+        return false;
+    };
+    for_each_expr(cx.tcx.hir().body(body_id).value, |e| {
+        if e.span.lo() < before.lo()
+            && let ExprKind::Binary(op, l, r) = e.kind
+            && op.node.is_comparison()
+            && ((SpanlessEq::new(cx).eq_expr(peel_casts(l), bound) && is_length(cx, r))
+                || (SpanlessEq::new(cx).eq_expr(peel_casts(r), bound) && is_length(cx, l)))
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
@@ -0,0 +1,112 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::{def_path_def_ids, fn_def_id, match_def_path, paths};
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::config::CrateType;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to functions modifying the process environment, such as `libc::setenv`,
+    /// in library crates and in crates that spawn threads.
+    ///
+    /// The functions are configured with the `env-mutating-functions` option.
+    ///
+    /// ### Why is this bad?
+    /// The C functions reading and writing the environment aren't thread-safe. Modifying it while
+    /// another thread calls `getenv`, directly or from inside a C library, is undefined behavior.
+    /// A library can't know which threads its caller runs.
+    ///
+    /// ### Known problems
+    /// The environment can be safely modified before the first thread is spawned, which this lint
+    /// doesn't detect.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// std::thread::spawn(worker);
+    /// unsafe { libc::setenv(c"TZ".as_ptr(), c"UTC".as_ptr(), 1) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// unsafe { libc::setenv(c"TZ".as_ptr(), c"UTC".as_ptr(), 1) };
+    /// std::thread::spawn(worker);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub RACY_ENV_MUTATION,
+    suspicious,
+    "modifying the environment in a library or a multithreaded program"
+}
+
+#[derive(Clone, Debug)]
+pub struct RacyEnvMutation {
+    functions: Vec<String>,
+    def_ids: DefIdSet,
+    calls: Vec<(HirId, Span, Symbol)>,
+    thread_spawn: Option<Span>,
+}
+
+impl RacyEnvMutation {
+    pub fn new(functions: Vec<String>) -> Self {
+        Self {
+            functions,
+            def_ids: DefIdSet::default(),
+            calls: Vec::new(),
+            thread_spawn: None,
+        }
+    }
+}
+
+impl_lint_pass!(RacyEnvMutation => [RACY_ENV_MUTATION]);
+
+impl<'tcx> LateLintPass<'tcx> for RacyEnvMutation {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.functions {
+            let segs: Vec<_> = path.split("::").collect();
+            self.def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(..) | ExprKind::MethodCall(..) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(def_id) = fn_def_id(cx, expr)
+        {
+            if self.def_ids.contains(&def_id) {
+                self.calls.push((expr.hir_id, expr.span, cx.tcx.item_name(def_id)));
+            } else if self.thread_spawn.is_none()
+                && (match_def_path(cx, def_id, &paths::THREAD_SPAWN)
+                    || match_def_path(cx, def_id, &paths::THREAD_BUILDER_SPAWN)
+                    || match_def_path(cx, def_id, &paths::THREAD_SCOPE))
+            {
+                self.thread_spawn = Some(expr.span);
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let is_library = !cx.tcx.crate_types().contains(&CrateType::Executable);
+        if !is_library && self.thread_spawn.is_none() {
+            return;
+        }
+        for &(hir_id, span, name) in &self.calls {
+            span_lint_hir_and_then(
+                cx,
+                RACY_ENV_MUTATION,
+                hir_id,
+                span,
+                &format!("calling `{name}` races with other threads accessing the environment"),
+                |diag| {
+                    if let Some(spawn) = self.thread_spawn {
+                        diag.span_note(spawn, "threads are spawned here");
+                    } else {
+                        diag.note("this is a library, so the caller may be running other threads");
+                    }
+                    diag.help("set up the environment before any thread is spawned, or pass the values explicitly");
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{
+    get_enclosing_block, get_parent_expr, is_null_check, match_def_path, path_def_id, path_to_local_id, paths,
+    peel_casts,
+};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, Expr, ExprKind, Local, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for pointers returned by `std::alloc::alloc`, `alloc_zeroed` or `realloc` and
+    /// bound to a local, possibly after a cast, which are used before being checked for null.
+    /// Dereferencing the result of these functions directly is linted as well.
+    ///
+    /// ### Why is this bad?
+    /// These functions return a null pointer when the allocation fails, like `malloc`. Writing
+    /// through it is undefined behavior, rather than the abort `Box` or `Vec` would cause.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let ptr = alloc(layout);
+    /// ptr.write_bytes(0, layout.size());
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let ptr = alloc(layout);
+    /// if ptr.is_null() {
+    ///     handle_alloc_error(layout);
+    /// }
+    /// ptr.write_bytes(0, layout.size());
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub RAW_ALLOC_WITHOUT_NULL_CHECK,
+    suspicious,
+    "using a pointer returned by `std::alloc::alloc` without checking it for null"
+}
+declare_lint_pass!(RawAllocWithoutNullCheck => [RAW_ALLOC_WITHOUT_NULL_CHECK]);
+
+impl<'tcx> LateLintPass<'tcx> for RawAllocWithoutNullCheck {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let PatKind::Binding(_, ptr_id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && !in_external_macro(cx.sess(), local.span)
+            && let Some(name) = raw_alloc_fn(cx, peel_casts(init))
+            && let Some(block) = get_enclosing_block(cx, local.hir_id)
+            && let Some(first_use) = for_each_expr(block, |e| {
+                if e.span.lo() > local.span.hi() && path_to_local_id(e, ptr_id) {
+                    ControlFlow::Break(e)
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            && !get_parent_expr(cx, first_use).is_some_and(|parent| is_null_check(cx, parent, ptr_id))
+            && !is_returned(cx, block, first_use)
+        {
+            span_lint_and_then(
+                cx,
+                RAW_ALLOC_WITHOUT_NULL_CHECK,
+                init.span,
+                &format!("the pointer returned by `{name}` is used without checking it for null"),
+                |diag| {
+                    diag.span_note(first_use.span, "the pointer is first used here");
+                    diag.help("check it with `is_null` and call `std::alloc::handle_alloc_error` if it is null");
+                },
+            );
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // the result dereferenced right away, e.g. `*(alloc(layout) as *mut u32) = 1`
+        if let ExprKind::Unary(UnOp::Deref, ptr) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(name) = raw_alloc_fn(cx, peel_casts(ptr))
+        {
+            span_lint_and_help(
+                cx,
+                RAW_ALLOC_WITHOUT_NULL_CHECK,
+                expr.span,
+                &format!("the pointer returned by `{name}` is dereferenced without checking it for null"),
+                None,
+                "bind it to a local, check it with `is_null` and call `std::alloc::handle_alloc_error` if it is null",
+            );
+        }
+    }
+}
+
+/// If `expr` is a call to `std::alloc::alloc`, `alloc_zeroed` or `realloc`, returns the name
+/// of the function.
+fn raw_alloc_fn(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<&'static str> {
+    if let ExprKind::Call(func, _) = expr.kind
+        && let Some(def_id) = path_def_id(cx, func)
+    {
+        if match_def_path(cx, def_id, &paths::ALLOC_ALLOC) {
+            Some("alloc")
+        } else if match_def_path(cx, def_id, &paths::ALLOC_ALLOC_ZEROED) {
+            Some("alloc_zeroed")
+        } else if match_def_path(cx, def_id, &paths::ALLOC_REALLOC) {
+            Some("realloc")
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+/// Whether `expr` is returned from `block`, as its tail expression or with `return`.
+fn is_returned(cx: &LateContext<'_>, block: &Block<'_>, expr: &Expr<'_>) -> bool {
+    block.expr.is_some_and(|tail| tail.hir_id == expr.hir_id)
+        || get_parent_expr(cx, expr).is_some_and(|parent| matches!(parent.kind, ExprKind::Ret(_)))
+}
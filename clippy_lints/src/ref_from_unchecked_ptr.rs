@@ -0,0 +1,114 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_node, is_foreign_call, is_null_check, path_to_local, peel_blocks, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::{Body, BorrowKind, Expr, ExprKind, HirId, Mutability, Node, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for references created with `&*p` or `&mut *p` from a raw pointer `p` received
+    /// from foreign code, i.e. returned by a foreign function or passed to an `extern` function,
+    /// when `p` isn't checked for null first.
+    ///
+    /// ### Why is this bad?
+    /// Foreign functions commonly return or pass null pointers, and a reference created from a
+    /// null pointer is undefined behavior.
+    ///
+    /// ### Known problems
+    /// A check anywhere before the reference is created in the same function is accepted, even
+    /// if it doesn't guard it.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let config = unsafe { &*ffi::get_config() };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let Some(config) = (unsafe { ffi::get_config().as_ref() }) else {
+    ///     return;
+    /// };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub REF_FROM_UNCHECKED_PTR,
+    suspicious,
+    "creating a reference from a raw pointer received from foreign code without checking it for null"
+}
+declare_lint_pass!(RefFromUncheckedPtr => [REF_FROM_UNCHECKED_PTR]);
+
+impl<'tcx> LateLintPass<'tcx> for RefFromUncheckedPtr {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::AddrOf(BorrowKind::Ref, mutability, inner) = expr.kind
+            && let ExprKind::Unary(UnOp::Deref, ptr) = inner.kind
+            && !expr.span.from_expansion()
+            && cx.typeck_results().expr_ty(ptr).is_unsafe_ptr()
+            && is_unchecked_foreign_ptr(cx, ptr, expr.span)
+        {
+            let method = if mutability == Mutability::Mut {
+                "as_mut"
+            } else {
+                "as_ref"
+            };
+            span_lint_and_help(
+                cx,
+                REF_FROM_UNCHECKED_PTR,
+                expr.span,
+                "creating a reference from a pointer received from foreign code without checking it for null",
+                None,
+                &format!(
+                    "use `{}.{method}()`, which returns `None` for a null pointer",
+                    snippet(cx, ptr.span, "..")
+                ),
+            );
+        }
+    }
+}
+
+/// Whether `ptr` is received from foreign code and, if it's a local binding, isn't checked for
+/// null before `span`.
+fn is_unchecked_foreign_ptr(cx: &LateContext<'_>, ptr: &Expr<'_>, span: Span) -> bool {
+    let ptr = peel_casts(ptr);
+    if is_foreign_call(cx, ptr) {
+        true
+    } else if let Some(ptr_id) = path_to_local(ptr)
+        && is_foreign_ptr_binding(cx, ptr_id)
+        && let Some(body_id) = cx.enclosing_body
+    {
+        !is_null_checked_before(cx, cx.tcx.hir().body(body_id), ptr_id, span)
+    } else {
+        false
+    }
+}
+
+/// Whether the local pointer `ptr_id` is checked for null before `span`.
+fn is_null_checked_before(cx: &LateContext<'_>, body: &Body<'_>, ptr_id: HirId, span: Span) -> bool {
+    for_each_expr(body.value, |e| {
+        if e.span.lo() < span.lo() && is_null_check(cx, e, ptr_id) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Whether the local pointer `ptr_id` is received from foreign code, i.e. it's a parameter of
+/// a function with a non-Rust ABI or it's initialized with the result of a foreign function.
+fn is_foreign_ptr_binding(cx: &LateContext<'_>, ptr_id: HirId) -> bool {
+    let hir = cx.tcx.hir();
+    match get_parent_node(cx.tcx, ptr_id) {
+        Some(Node::Param(_)) => {
+            let owner = hir.local_def_id_to_hir_id(hir.enclosing_body_owner(ptr_id));
+            hir.fn_sig_by_hir_id(owner)
+                .is_some_and(|sig| !matches!(sig.header.abi, Abi::Rust))
+        },
+        Some(Node::Local(local)) => local
+            .init
+            .is_some_and(|init| is_foreign_call(cx, peel_casts(peel_blocks(init)))),
+        _ => false,
+    }
+}
@@ -0,0 +1,84 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use rustc_errors::Applicability;
+use rustc_hir::{FnRetTy, ForeignItemKind, Item, ItemKind, MutTy, Mutability, Ty, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for references in the parameter and return types of functions declared in
+    /// `extern` blocks.
+    ///
+    /// ### Why is this bad?
+    /// C functions take and return raw pointers, which may be null, dangling or unaligned.
+    /// A reference asserts that none of this is the case, and getting it wrong is undefined
+    /// behavior even if the reference is never used. Such declarations are usually written
+    /// by hand, and the C side rarely documents these guarantees.
+    ///
+    /// ### Known problems
+    /// Some C functions do document that a pointer is never null and always valid, in which
+    /// case a reference is fine.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// extern "C" {
+    ///     fn frobnicate(widget: &Widget) -> &mut Widget;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// extern "C" {
+    ///     fn frobnicate(widget: *const Widget) -> *mut Widget;
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub REF_IN_FOREIGN_FN,
+    pedantic,
+    "references in the signature of a function declared in an `extern` block"
+}
+declare_lint_pass!(RefInForeignFn => [REF_IN_FOREIGN_FN]);
+
+impl<'tcx> LateLintPass<'tcx> for RefInForeignFn {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::ForeignMod { abi, items } = item.kind
+            && abi != Abi::Rust
+            && !in_external_macro(cx.sess(), item.span)
+        {
+            for foreign_item in items {
+                if let ForeignItemKind::Fn(decl, ..) = cx.tcx.hir().foreign_item(foreign_item.id).kind {
+                    for ty in decl.inputs {
+                        check_ty(cx, ty);
+                    }
+                    if let FnRetTy::Return(ty) = decl.output {
+                        check_ty(cx, ty);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_ty(cx: &LateContext<'_>, ty: &Ty<'_>) {
+    if let TyKind::Ref(_, MutTy { ty: inner, mutbl }) = ty.kind
+        && !ty.span.from_expansion()
+    {
+        let mut applicability = Applicability::MaybeIncorrect;
+        let inner = snippet_with_applicability(cx, inner.span, "..", &mut applicability);
+        let ptr = match mutbl {
+            Mutability::Not => "*const",
+            Mutability::Mut => "*mut",
+        };
+        span_lint_and_sugg(
+            cx,
+            REF_IN_FOREIGN_FN,
+            ty.span,
+            "reference in the signature of a foreign function",
+            "use a raw pointer instead",
+            format!("{ptr} {inner}"),
+            applicability,
+        );
+    }
+}
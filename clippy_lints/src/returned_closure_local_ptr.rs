@@ -0,0 +1,127 @@
+use crate::utils::buffer_owner::buffer_owner;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{expr_or_init, path_to_local, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, BorrowKind, Expr, ExprKind, FnDecl, HirId, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for closures returned from a function, directly or wrapped e.g. in a `Box`, which
+    /// dereference a raw pointer created outside of the closure to a local of the function.
+    ///
+    /// ### Why is this bad?
+    /// Moving the pointer into the closure doesn't move what it points to. The local is dropped
+    /// when the function returns, so the closure dereferences a dangling pointer whenever it's
+    /// called. A pointer into the buffer of a `String`, `Vec`, `Box` or `CString` is fine if the
+    /// owner is moved into the closure as well.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn counter() -> impl Fn() -> i32 {
+    ///     let x = 5;
+    ///     let p = &x as *const i32;
+    ///     move || unsafe { *p }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn counter() -> impl Fn() -> i32 {
+    ///     let x = 5;
+    ///     move || unsafe { *(&x as *const i32) }
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub RETURNED_CLOSURE_LOCAL_PTR,
+    suspicious,
+    "returning a closure dereferencing a pointer to a local of the enclosing function"
+}
+declare_lint_pass!(ReturnedClosureLocalPtr => [RETURNED_CLOSURE_LOCAL_PTR]);
+
+impl<'tcx> LateLintPass<'tcx> for ReturnedClosureLocalPtr {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        _: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure) || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if let ExprKind::Block(block, _) = body.value.kind
+            && let Some(tail) = block.expr
+        {
+            check_returned_closure(cx, tail);
+        }
+        for_each_expr(body.value, |e| {
+            if let ExprKind::Ret(Some(ret)) = e.kind {
+                check_returned_closure(cx, ret);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+}
+
+fn check_returned_closure(cx: &LateContext<'_>, ret: &Expr<'_>) {
+    // `move || ..`, or the closure passed to e.g. `Box::new`
+    let closure = match ret.kind {
+        ExprKind::Closure(closure) => closure,
+        ExprKind::Call(_, [arg]) if let ExprKind::Closure(closure) = arg.kind => closure,
+        _ => return,
+    };
+    let Some(upvars) = cx.tcx.upvars_mentioned(closure.def_id) else {
+        return;
+    };
+    for_each_expr(cx.tcx.hir().body(closure.body).value, |e| {
+        if let ExprKind::Unary(UnOp::Deref, ptr) = e.kind
+            && let ptr = peel_casts(ptr)
+            && let Some(ptr_id) = path_to_local(ptr)
+            && upvars.contains_key(&ptr_id)
+            && let init = peel_casts(expr_or_init(cx, ptr))
+            && init.hir_id != ptr.hir_id
+            && let Some(pointee) = dangling_pointee(cx, init, |id| upvars.contains_key(&id))
+        {
+            span_lint_and_then(
+                cx,
+                RETURNED_CLOSURE_LOCAL_PTR,
+                e.span,
+                "dereferencing a pointer to a local of the enclosing function in a returned closure",
+                |diag| {
+                    diag.span_note(init.span, "the pointer is created here");
+                    diag.span_note(pointee.span, "this is dropped when the function returns");
+                    diag.help("move the value into the closure and create the pointer inside of it");
+                },
+            );
+        }
+        ControlFlow::<()>::Continue(())
+    });
+}
+
+/// If the pointer `init` points to a local, or into a buffer owned by a local or temporary that
+/// isn't captured, returns the expression of the local or temporary.
+fn dangling_pointee<'a>(
+    cx: &LateContext<'_>,
+    init: &'a Expr<'a>,
+    is_captured: impl Fn(HirId) -> bool,
+) -> Option<&'a Expr<'a>> {
+    match init.kind {
+        ExprKind::AddrOf(BorrowKind::Raw | BorrowKind::Ref, _, inner) => {
+            path_to_local(inner).is_some().then_some(inner)
+        },
+        ExprKind::MethodCall(method, receiver, [], _)
+            if matches!(method.ident.name.as_str(), "as_ptr" | "as_mut_ptr") =>
+        {
+            buffer_owner(cx, receiver).filter(|owner| !path_to_local(owner).is_some_and(&is_captured))
+        },
+        _ => None,
+    }
+}
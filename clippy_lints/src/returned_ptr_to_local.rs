@@ -0,0 +1,107 @@
+use crate::utils::buffer_owner::buffer_owner;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{expr_or_init, path_to_local, peel_casts};
+use core::ops::ControlFlow;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions returning a raw pointer obtained with `as_ptr()` or `as_mut_ptr()`
+    /// from a local or temporary `String`, `Vec`, `Box` or `CString`.
+    ///
+    /// ### Why is this bad?
+    /// The value owning the buffer is dropped when the function returns, which frees the buffer.
+    /// The returned pointer dangles right away, and using it is a use after free.
+    ///
+    /// ### Known problems
+    /// A local passed to `std::mem::forget` before returning isn't dropped, but is still linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::ffi::{c_char, CString};
+    /// fn name(id: u32) -> *const c_char {
+    ///     let name = CString::new(format!("item-{id}")).unwrap();
+    ///     name.as_ptr()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::ffi::{c_char, CString};
+    /// fn name(id: u32) -> *mut c_char {
+    ///     // free it with `CString::from_raw`
+    ///     CString::new(format!("item-{id}")).unwrap().into_raw()
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub RETURNED_PTR_TO_LOCAL,
+    suspicious,
+    "returning a pointer into a buffer owned by a local that is dropped when the function returns"
+}
+declare_lint_pass!(ReturnedPtrToLocal => [RETURNED_PTR_TO_LOCAL]);
+
+impl<'tcx> LateLintPass<'tcx> for ReturnedPtrToLocal {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        _: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure) || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if let ExprKind::Block(block, _) = body.value.kind
+            && let Some(tail) = block.expr
+        {
+            check_returned(cx, tail);
+        }
+        for_each_expr(body.value, |e| {
+            if let ExprKind::Ret(Some(ret)) = e.kind {
+                check_returned(cx, ret);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+}
+
+fn check_returned(cx: &LateContext<'_>, ret: &Expr<'_>) {
+    if cx.typeck_results().expr_ty(ret).is_unsafe_ptr()
+        && let ptr = peel_casts(expr_or_init(cx, peel_casts(ret)))
+        && let ExprKind::MethodCall(method, receiver, [], _) = ptr.kind
+        && matches!(method.ident.name.as_str(), "as_ptr" | "as_mut_ptr")
+        && let Some(owner) = buffer_owner(cx, receiver)
+    {
+        span_lint_and_then(
+            cx,
+            RETURNED_PTR_TO_LOCAL,
+            ret.span,
+            "returning a pointer into a buffer that is freed when the function returns",
+            |diag| {
+                if ptr.hir_id != ret.hir_id {
+                    diag.span_note(ptr.span, "the pointer is taken here");
+                }
+                if let Some(id) = path_to_local(owner) {
+                    diag.span_note(
+                        cx.tcx.hir().span(id),
+                        "the buffer is owned by this local, which is dropped when the function returns",
+                    );
+                } else {
+                    diag.span_note(
+                        owner.span,
+                        "the buffer is owned by this temporary, which is dropped right away",
+                    );
+                }
+                diag.help("return the owning value, or give up its ownership with `into_raw` or `leak`");
+            },
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{match_def_path, path_def_id, paths, peel_casts};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `ptr::copy` and `ptr::copy_nonoverlapping` calls that copy the bytes of a
+    /// struct or enum without a defined layout (i.e. without `#[repr(C)]`, `#[repr(transparent)]`
+    /// or a primitive representation) into a byte buffer.
+    ///
+    /// ### Why is this bad?
+    /// The layout of a `repr(Rust)` type is unspecified: the compiler is free to reorder
+    /// fields and choose padding, and may do so differently between compiler versions.
+    /// Bytes copied out of such a value and handed to C code, written to a file or sent
+    /// over the network can't be reliably interpreted by anyone else.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::mem::size_of;
+    /// struct Header {
+    ///     kind: u8,
+    ///     len: u32,
+    /// }
+    ///
+    /// let header = Header { kind: 1, len: 4 };
+    /// let mut buf = [0u8; 16];
+    /// unsafe {
+    ///     std::ptr::copy_nonoverlapping(
+    ///         &header as *const Header as *const u8,
+    ///         buf.as_mut_ptr(),
+    ///         size_of::<Header>(),
+    ///     );
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::mem::size_of;
+    /// #[repr(C)]
+    /// struct Header {
+    ///     kind: u8,
+    ///     len: u32,
+    /// }
+    /// # let header = Header { kind: 1, len: 4 };
+    /// # let mut buf = [0u8; 16];
+    /// # unsafe {
+    /// #     std::ptr::copy_nonoverlapping(
+    /// #         &header as *const Header as *const u8,
+    /// #         buf.as_mut_ptr(),
+    /// #         size_of::<Header>(),
+    /// #     );
+    /// # }
+    /// ```
+    /// or serialize the fields one by one, e.g. with `to_le_bytes`.
+    #[clippy::version = "1.74.0"]
+    pub RUST_LAYOUT_BYTE_COPY,
+    nursery,
+    "copying the bytes of a type without a defined layout"
+}
+declare_lint_pass!(RustLayoutByteCopy => [RUST_LAYOUT_BYTE_COPY]);
+
+impl<'tcx> LateLintPass<'tcx> for RustLayoutByteCopy {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(func, [src, _dst, _count]) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(def_id) = path_def_id(cx, func)
+            && (match_def_path(cx, def_id, &paths::PTR_COPY_NONOVERLAPPING)
+                || match_def_path(cx, def_id, &paths::PTR_COPY))
+            && let Some(elem_ty) = cx.typeck_results().node_args(func.hir_id).types().next()
+            && is_byte(elem_ty)
+            && let Some(src_ty) = pointee_ty(cx.typeck_results().expr_ty(peel_casts(src)))
+            && let ty::Adt(adt, _) = src_ty.kind()
+            && (adt.is_struct() || adt.is_enum())
+            && !has_defined_layout(adt.repr())
+        {
+            span_lint_and_help(
+                cx,
+                RUST_LAYOUT_BYTE_COPY,
+                src.span,
+                &format!("copying the bytes of `{src_ty}`, which has an unspecified layout"),
+                None,
+                "add `#[repr(C)]` to the type, or serialize its fields one by one",
+            );
+        }
+    }
+}
+
+fn is_byte(ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Uint(ty::UintTy::U8) | ty::Int(ty::IntTy::I8))
+}
+
+fn pointee_ty(ty: Ty<'_>) -> Option<Ty<'_>> {
+    match *ty.kind() {
+        ty::Ref(_, ty, _) | ty::RawPtr(ty::TypeAndMut { ty, .. }) => Some(ty),
+        _ => None,
+    }
+}
+
+fn has_defined_layout(repr: ty::ReprOptions) -> bool {
+    repr.c() || repr.transparent() || repr.simd() || repr.int.is_some()
+}
@@ -0,0 +1,69 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::source::snippet;
+use clippy_utils::{fn_def_id, is_rust_abi, may_be_negative};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, UintTy};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for signed integers being cast to `usize` (`size_t` in C) in the arguments of a
+    /// function with a foreign ABI, such as `malloc` or `memcpy`, when the value may be
+    /// negative.
+    ///
+    /// ### Why is this bad?
+    /// A negative value wraps around to a huge size, which turns into an oversized allocation
+    /// or an out of bounds access on the C side.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let len: i32 = header.len;
+    /// let buf = unsafe { libc::malloc(len as usize) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let len = usize::try_from(header.len)?;
+    /// let buf = unsafe { libc::malloc(len) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub SIGNED_LEN_TO_FFI,
+    suspicious,
+    "casting a signed integer that may be negative to a `usize` argument of a foreign function"
+}
+declare_lint_pass!(SignedLenToFfi => [SIGNED_LEN_TO_FFI]);
+
+impl<'tcx> LateLintPass<'tcx> for SignedLenToFfi {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, args) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(def_id) = fn_def_id(cx, expr)
+        {
+            let sig = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder();
+            if is_rust_abi(sig.abi) {
+                return;
+            }
+            for (arg, param_ty) in args.iter().zip(sig.inputs()) {
+                if *param_ty.kind() == ty::Uint(UintTy::Usize)
+                    && let ExprKind::Cast(value, _) = arg.kind
+                    && !arg.span.from_expansion()
+                    && may_be_negative(cx, value)
+                {
+                    span_lint_and_note(
+                        cx,
+                        SIGNED_LEN_TO_FFI,
+                        arg.span,
+                        "casting a signed integer that may be negative to a `usize` argument of a foreign function",
+                        None,
+                        &format!(
+                            "a negative value wraps around to a huge size; convert it with `usize::try_from({})` \
+                            and handle the error instead",
+                            snippet(cx, value.span, "..")
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
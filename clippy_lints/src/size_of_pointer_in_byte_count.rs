@@ -0,0 +1,119 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::fn_def_id;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `size_of::<&T>()` or `size_of::<*const T>()`, i.e. the size of a pointer, used
+    /// in the byte count passed to an allocation or copy function, such as `malloc`, `memcpy`
+    /// or `Layout::from_size_align`.
+    ///
+    /// ### Why is this bad?
+    /// The size of the value the pointer points to was most likely meant. Allocating or copying
+    /// only the size of a pointer is a buffer overflow as soon as the value is larger.
+    ///
+    /// ### Known problems
+    /// Buffers of pointers are correctly sized with the size of a pointer.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let header = libc::malloc(size_of::<*const Header>()).cast::<Header>();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let header = libc::malloc(size_of::<Header>()).cast::<Header>();
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub SIZE_OF_POINTER_IN_BYTE_COUNT,
+    pedantic,
+    "using the size of a pointer where the size of its pointee is likely expected"
+}
+declare_lint_pass!(SizeOfPointerInByteCount => [SIZE_OF_POINTER_IN_BYTE_COUNT]);
+
+impl<'tcx> LateLintPass<'tcx> for SizeOfPointerInByteCount {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, args) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(def_id) = fn_def_id(cx, expr)
+            && let Some(index) = byte_count_arg(cx, def_id)
+            && let Some(count) = args.get(index)
+            && let Some((size_of, ptr_ty)) = find_size_of_ptr(cx, count)
+            && let Some(pointee) = ptr_ty.builtin_deref(true)
+        {
+            let help = if pointee.ty.is_sized(cx.tcx, cx.param_env) {
+                format!(
+                    "if the size of the pointee was meant, use `size_of::<{}>()`",
+                    pointee.ty
+                )
+            } else {
+                "if the size of the pointee was meant, use `size_of_val` on it".to_owned()
+            };
+            span_lint_and_help(
+                cx,
+                SIZE_OF_POINTER_IN_BYTE_COUNT,
+                size_of.span,
+                &format!(
+                    "passing the size of the pointer `{ptr_ty}` to `{}`",
+                    cx.tcx.item_name(def_id)
+                ),
+                None,
+                &help,
+            );
+        }
+    }
+}
+
+/// Returns the index of the byte count argument of the allocation or copy function `def_id`.
+fn byte_count_arg(cx: &LateContext<'_>, def_id: DefId) -> Option<usize> {
+    let name = cx.tcx.item_name(def_id);
+    if cx.tcx.is_foreign_item(def_id) {
+        match name.as_str() {
+            "malloc" => Some(0),
+            "aligned_alloc" | "calloc" | "realloc" => Some(1),
+            "memcpy" | "memmove" | "memset" => Some(2),
+            _ => None,
+        }
+    } else if matches!(name.as_str(), "from_size_align" | "from_size_align_unchecked")
+        && let Some(impl_id) = cx.tcx.impl_of_method(def_id)
+        && is_type_lang_item(
+            cx,
+            cx.tcx.type_of(impl_id).instantiate_identity(),
+            LangItem::AllocLayout,
+        )
+    {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+/// Finds a `size_of::<P>()` call in the byte count `expr`, where `P` is a pointer or reference
+/// type, and returns it with `P`.
+fn find_size_of_ptr<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<(&'tcx Expr<'tcx>, Ty<'tcx>)> {
+    match expr.kind {
+        ExprKind::Call(func, []) => {
+            if let ExprKind::Path(ref qpath) = func.kind
+                && let Some(def_id) = cx.qpath_res(qpath, func.hir_id).opt_def_id()
+                && cx.tcx.is_diagnostic_item(sym::mem_size_of, def_id)
+                && let Some(ty) = cx.typeck_results().node_args(func.hir_id).types().next()
+                && matches!(ty.kind(), ty::Ref(..) | ty::RawPtr(_))
+            {
+                Some((expr, ty))
+            } else {
+                None
+            }
+        },
+        ExprKind::Binary(op, left, right) if matches!(op.node, BinOpKind::Mul | BinOpKind::Add) => {
+            find_size_of_ptr(cx, left).or_else(|| find_size_of_ptr(cx, right))
+        },
+        ExprKind::Cast(expr, _) => find_size_of_ptr(cx, expr),
+        _ => None,
+    }
+}
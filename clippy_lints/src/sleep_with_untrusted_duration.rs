@@ -0,0 +1,100 @@
+use crate::utils::fn_set::FnSet;
+use crate::utils::untrusted_values::{is_checked_before, untrusted_origin, UntrustedOrigin};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::expr_or_init;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `std::thread::sleep` or `tokio::time::sleep` with a `Duration` built
+    /// from an integer decoded from raw bytes, e.g. with `u64::from_le_bytes`, or cast from a
+    /// signed integer that may be negative, which isn't capped with `min` or `clamp`, or compared
+    /// with anything, beforehand.
+    ///
+    /// The sleep functions are configured with the `sleep-functions` option.
+    ///
+    /// ### Why is this bad?
+    /// A duration read from a file or the network is under the control of whoever wrote the
+    /// data, and a negative value wraps around to a huge one. Either way the thread can be made
+    /// to sleep for an arbitrarily long time, which is an easy denial of service.
+    ///
+    /// ### Known problems
+    /// Only durations created with one of the `Duration` constructors, directly or through a
+    /// local binding, are checked. Any comparison of the value counts as a check, even one that
+    /// doesn't bound it from above.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let secs = u64::from_le_bytes(header);
+    /// std::thread::sleep(Duration::from_secs(secs));
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let secs = u64::from_le_bytes(header);
+    /// std::thread::sleep(Duration::from_secs(secs.min(MAX_RETRY_DELAY)));
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub SLEEP_WITH_UNTRUSTED_DURATION,
+    restriction,
+    "sleeping for an unbounded duration decoded from bytes or cast from a signed integer"
+}
+
+#[derive(Clone, Debug)]
+pub struct SleepWithUntrustedDuration {
+    functions: Vec<String>,
+    fns: FnSet,
+}
+
+impl SleepWithUntrustedDuration {
+    pub fn new(functions: Vec<String>) -> Self {
+        Self {
+            functions,
+            fns: FnSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(SleepWithUntrustedDuration => [SLEEP_WITH_UNTRUSTED_DURATION]);
+
+impl<'tcx> LateLintPass<'tcx> for SleepWithUntrustedDuration {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        self.fns.extend(cx, &self.functions);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, [duration]) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && self.fns.contains_callee(cx, expr)
+            && let ctor = expr_or_init(cx, duration)
+            && let ExprKind::Call(_, args) = ctor.kind
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(ctor), sym::Duration)
+            && let Some(body_id) = cx.enclosing_body
+            && let body = cx.tcx.hir().body(body_id).value
+            && let Some((origin, kind)) = args.iter().find_map(|arg| {
+                untrusted_origin(cx, arg).filter(|&(origin, _)| {
+                    !is_checked_before(cx, body, arg, expr, None) && !is_checked_before(cx, body, origin, expr, None)
+                })
+            })
+        {
+            let (msg, note) = match kind {
+                UntrustedOrigin::Decoded => (
+                    "sleeping for a duration decoded from raw bytes",
+                    "the duration is decoded from raw bytes here",
+                ),
+                UntrustedOrigin::SignedCast => (
+                    "sleeping for a duration cast from a signed integer",
+                    "the duration is cast from a signed integer that may be negative here",
+                ),
+            };
+            span_lint_and_then(cx, SLEEP_WITH_UNTRUSTED_DURATION, duration.span, msg, |diag| {
+                diag.span_note(origin.span, note);
+                diag.help("cap the duration with `min` or `clamp` first");
+            });
+        }
+    }
+}
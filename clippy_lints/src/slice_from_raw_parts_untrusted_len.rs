@@ -0,0 +1,77 @@
+use crate::utils::untrusted_values::{is_checked_before, untrusted_origin, UntrustedOrigin};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{fn_def_id, match_def_path, paths};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `slice::from_raw_parts` and `slice::from_raw_parts_mut` calls whose length is
+    /// decoded from raw bytes, e.g. with `u32::from_le_bytes`, or cast from a signed integer that
+    /// may be negative, and isn't compared with anything beforehand.
+    ///
+    /// ### Why is this bad?
+    /// The slice covers `len * size_of::<T>()` bytes. A length read from a file or the network
+    /// is under the control of whoever wrote the data, and a negative length wraps around to a
+    /// huge one. Either way the slice reaches past the end of the buffer, or the byte size
+    /// overflows, and reading it is undefined behavior.
+    ///
+    /// ### Known problems
+    /// Any comparison of the length, or of the value it's computed from, counts as a check,
+    /// even one against something other than the size of the buffer.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let len = u32::from_le_bytes(header) as usize;
+    /// let payload = unsafe { std::slice::from_raw_parts(buf.as_ptr().add(4), len) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let len = u32::from_le_bytes(header) as usize;
+    /// if len > buf.len() - 4 {
+    ///     return Err(Error::Truncated);
+    /// }
+    /// let payload = unsafe { std::slice::from_raw_parts(buf.as_ptr().add(4), len) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub SLICE_FROM_RAW_PARTS_UNTRUSTED_LEN,
+    suspicious,
+    "creating a slice from raw parts with an unchecked length decoded from bytes or cast from a signed integer"
+}
+declare_lint_pass!(SliceFromRawPartsUntrustedLen => [SLICE_FROM_RAW_PARTS_UNTRUSTED_LEN]);
+
+impl<'tcx> LateLintPass<'tcx> for SliceFromRawPartsUntrustedLen {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, [_, len]) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(def_id) = fn_def_id(cx, expr)
+            && (match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS)
+                || match_def_path(cx, def_id, &paths::SLICE_FROM_RAW_PARTS_MUT))
+            && let Some((origin, kind)) = untrusted_origin(cx, len)
+            && let Some(body_id) = cx.enclosing_body
+            && let body = cx.tcx.hir().body(body_id).value
+            && !is_checked_before(cx, body, len, expr, None)
+            && !is_checked_before(cx, body, origin, expr, None)
+        {
+            let (msg, note) = match kind {
+                UntrustedOrigin::Decoded => (
+                    "creating a slice from raw parts with a length decoded from raw bytes",
+                    "the length is decoded from raw bytes here",
+                ),
+                UntrustedOrigin::SignedCast => (
+                    "creating a slice from raw parts with a length cast from a signed integer",
+                    "the length is cast from a signed integer that may be negative here",
+                ),
+            };
+            span_lint_and_then(cx, SLICE_FROM_RAW_PARTS_UNTRUSTED_LEN, len.span, msg, |diag| {
+                diag.span_note(origin.span, note);
+                diag.note(
+                    "the slice covers `len * size_of::<T>()` bytes, which may overflow or reach past the end of the buffer",
+                );
+                diag.help("compare the length with the size of the buffer first");
+            });
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::stack_local_address;
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+use crate::utils::conf;
+use crate::utils::retaining_functions::RetainingFunctions;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the address of a stack local, or a pointer into a stack array, being passed
+    /// to a function that is known to retain the pointer beyond the call, e.g. a callback
+    /// registration API.
+    ///
+    /// The functions, and optionally which of their arguments are retained, are configured with
+    /// the `retaining-functions` option.
+    ///
+    /// ### Why is this bad?
+    /// The local is dropped when the enclosing function returns, but the callee keeps the
+    /// pointer around. Any later access through it is a use of a dangling pointer.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn init() {
+    ///     let config = Config::default();
+    ///     unsafe { register_config(&config as *const Config) };
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// static CONFIG: Config = Config::new();
+    ///
+    /// fn init() {
+    ///     unsafe { register_config(&CONFIG as *const Config) };
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub STACK_PTR_ESCAPES_VIA_CALL,
+    suspicious,
+    "passing the address of a stack local to a function that retains it"
+}
+
+#[derive(Clone, Debug)]
+pub struct StackPtrEscapesViaCall {
+    retaining: RetainingFunctions,
+}
+
+impl StackPtrEscapesViaCall {
+    pub fn new(conf_retaining: Vec<conf::RetainingFunction>) -> Self {
+        Self {
+            retaining: RetainingFunctions::new(conf_retaining),
+        }
+    }
+}
+
+impl_lint_pass!(StackPtrEscapesViaCall => [STACK_PTR_ESCAPES_VIA_CALL]);
+
+impl<'tcx> LateLintPass<'tcx> for StackPtrEscapesViaCall {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        self.retaining.resolve(cx);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        for arg in self.retaining.retained_args(cx, expr) {
+            if let Some(local) = stack_local_address(cx, arg) {
+                let name = cx.tcx.hir().name(local);
+                span_lint_and_help(
+                    cx,
+                    STACK_PTR_ESCAPES_VIA_CALL,
+                    arg.span,
+                    &format!("passing the address of the stack local `{name}` to a function that retains it"),
+                    None,
+                    &format!(
+                        "the pointer dangles once `{name}` goes out of scope; store the value in a `static` or on the heap instead"
+                    ),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::peel_ptr_casts;
+use rustc_hir::{Expr, ExprKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+use crate::utils::conf;
+use crate::utils::retaining_functions::RetainingFunctions;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the address of a temporary, e.g. `&make_config()` or `make_vec().as_ptr()`,
+    /// being passed to a function that is known to retain the pointer beyond the call.
+    ///
+    /// The functions are configured with the `retaining-functions` option, like for
+    /// `stack_ptr_escapes_via_call`.
+    ///
+    /// ### Why is this bad?
+    /// The temporary is dropped at the end of the statement, so the pointer the callee keeps
+    /// dangles as soon as the call returns.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// unsafe { set_buffer(&make_buffer() as *const Buffer) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let buffer = Box::leak(Box::new(make_buffer()));
+    /// unsafe { set_buffer(buffer as *const Buffer) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub TEMPORARY_PTR_ESCAPES_VIA_CALL,
+    suspicious,
+    "passing the address of a temporary to a function that retains it"
+}
+
+#[derive(Clone, Debug)]
+pub struct TemporaryPtrEscapesViaCall {
+    retaining: RetainingFunctions,
+}
+
+impl TemporaryPtrEscapesViaCall {
+    pub fn new(conf_retaining: Vec<conf::RetainingFunction>) -> Self {
+        Self {
+            retaining: RetainingFunctions::new(conf_retaining),
+        }
+    }
+}
+
+impl_lint_pass!(TemporaryPtrEscapesViaCall => [TEMPORARY_PTR_ESCAPES_VIA_CALL]);
+
+impl<'tcx> LateLintPass<'tcx> for TemporaryPtrEscapesViaCall {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        self.retaining.resolve(cx);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        for arg in self.retaining.retained_args(cx, expr) {
+            if let Some(temporary) = temporary_address(cx, arg) {
+                span_lint_and_then(
+                    cx,
+                    TEMPORARY_PTR_ESCAPES_VIA_CALL,
+                    arg.span,
+                    "passing the address of a temporary to a function that retains it",
+                    |diag| {
+                        diag.span_note(temporary.span, "this temporary is dropped at the end of the statement");
+                        diag.help("store the value in a `static` or on the heap instead");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// If `expr` takes the address of a temporary, or points into the data of one through
+/// `as_ptr`/`as_mut_ptr`, returns the temporary. Shared borrows of constants are promoted to
+/// `'static`, so they aren't temporaries.
+fn temporary_address<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    match peel_ptr_casts(expr).kind {
+        ExprKind::AddrOf(_, mutbl, value)
+            if !value.is_syntactic_place_expr()
+                && (mutbl == Mutability::Mut || constant(cx, cx.typeck_results(), value).is_none()) =>
+        {
+            Some(value)
+        },
+        ExprKind::MethodCall(method, receiver, [], _)
+            if matches!(method.ident.as_str(), "as_ptr" | "as_mut_ptr")
+                // Data behind a reference or a raw pointer isn't owned by the receiver.
+                && !cx.typeck_results().expr_ty(receiver).is_any_ptr()
+                && !receiver.is_syntactic_place_expr() =>
+        {
+            Some(receiver)
+        },
+        _ => None,
+    }
+}
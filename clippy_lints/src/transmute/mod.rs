@@ -1,5 +1,7 @@
 mod crosspointer_transmute;
+mod transmute_copy_to_larger_type;
 mod transmute_float_to_int;
+mod transmute_fn_ptr_qualifiers;
 mod transmute_int_to_bool;
 mod transmute_int_to_char;
 mod transmute_int_to_float;
@@ -10,6 +12,7 @@ mod transmute_ptr_to_ptr;
 mod transmute_ptr_to_ref;
 mod transmute_ref_to_ref;
 mod transmute_undefined_repr;
+mod transmute_untrusted_int_to_enum;
 mod transmutes_expressible_as_ptr_casts;
 mod transmuting_null;
 mod unsound_collection_transmute;
@@ -17,7 +20,7 @@ mod useless_transmute;
 mod utils;
 mod wrong_transmute;
 
-use clippy_utils::in_constant;
+use clippy_utils::{in_constant, match_def_path, path_def_id, paths};
 use clippy_utils::msrvs::Msrv;
 use if_chain::if_chain;
 use rustc_hir::{Expr, ExprKind, QPath};
@@ -464,6 +467,110 @@ declare_clippy_lint! {
     "transmute results in a null function pointer, which is undefined behavior"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for transmutes between function pointer types that remove the `unsafe`
+    /// qualifier or change the ABI, when the resulting function pointer is then called.
+    ///
+    /// ### Why is this bad?
+    /// Removing `unsafe` lets the function be called without an `unsafe` block, hiding the
+    /// preconditions the caller has to uphold. Calling a function through a pointer with a
+    /// different ABI is undefined behavior.
+    ///
+    /// ### Example
+    /// ```rust
+    /// unsafe extern "C" fn abs(x: i32) -> i32 {
+    ///     x.abs()
+    /// }
+    ///
+    /// let f: fn(i32) -> i32 = unsafe { std::mem::transmute(abs as unsafe extern "C" fn(i32) -> i32) };
+    /// f(-1);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// unsafe extern "C" fn abs(x: i32) -> i32 {
+    ///     x.abs()
+    /// }
+    ///
+    /// let f: unsafe extern "C" fn(i32) -> i32 = abs;
+    /// unsafe { f(-1) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub TRANSMUTE_FN_PTR_QUALIFIERS,
+    suspicious,
+    "transmuting a function pointer to remove `unsafe` or change its ABI, and calling it"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to `mem::transmute_copy::<Src, Dst>` where `Dst` is larger than `Src`.
+    ///
+    /// ### Why is this bad?
+    /// `transmute_copy` reads `size_of::<Dst>()` bytes from the source, which would go past its
+    /// end. The standard library asserts against this, so the call always panics.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let small: u32 = 1;
+    /// let big: u64 = unsafe { std::mem::transmute_copy(&small) };
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let small: u32 = 1;
+    /// let big = u64::from(small);
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub TRANSMUTE_COPY_TO_LARGER_TYPE,
+    correctness,
+    "`transmute_copy` to a type larger than the source"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `transmute` and `transmute_copy` calls turning an integer decoded from raw
+    /// bytes, e.g. with `u32::from_le_bytes`, into a fieldless enum, when the integer isn't
+    /// compared with anything beforehand.
+    ///
+    /// ### Why is this bad?
+    /// An integer read from a file or the network can hold any value, and transmuting a value
+    /// that isn't the discriminant of one of the variants is undefined behavior.
+    ///
+    /// ### Known problems
+    /// Any comparison of the integer counts as a check, even one that doesn't rule out all the
+    /// invalid values.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[repr(u32)]
+    /// enum Kind {
+    ///     Request = 1,
+    ///     Response = 2,
+    /// }
+    ///
+    /// let kind: Kind = unsafe { std::mem::transmute(u32::from_le_bytes(header)) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// impl TryFrom<u32> for Kind {
+    ///     type Error = u32;
+    ///
+    ///     fn try_from(value: u32) -> Result<Self, u32> {
+    ///         match value {
+    ///             1 => Ok(Self::Request),
+    ///             2 => Ok(Self::Response),
+    ///             _ => Err(value),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let kind = Kind::try_from(u32::from_le_bytes(header))?;
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub TRANSMUTE_UNTRUSTED_INT_TO_ENUM,
+    suspicious,
+    "transmuting an integer decoded from raw bytes into an enum"
+}
+
 pub struct Transmute {
     msrv: Msrv,
 }
@@ -485,6 +592,9 @@ impl_lint_pass!(Transmute => [
     TRANSMUTE_UNDEFINED_REPR,
     TRANSMUTING_NULL,
     TRANSMUTE_NULL_TO_FN,
+    TRANSMUTE_FN_PTR_QUALIFIERS,
+    TRANSMUTE_COPY_TO_LARGER_TYPE,
+    TRANSMUTE_UNTRUSTED_INT_TO_ENUM,
 ]);
 impl Transmute {
     #[must_use]
@@ -494,6 +604,14 @@ impl Transmute {
 }
 impl<'tcx> LateLintPass<'tcx> for Transmute {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+        if let ExprKind::Call(path_expr, [arg]) = e.kind
+            && path_def_id(cx, path_expr).is_some_and(|def_id| match_def_path(cx, def_id, &paths::MEM_TRANSMUTE_COPY))
+        {
+            transmute_copy_to_larger_type::check(cx, e, path_expr);
+            transmute_untrusted_int_to_enum::check_copy(cx, e, path_expr, arg);
+            return;
+        }
+
         if_chain! {
             if let ExprKind::Call(path_expr, [arg]) = e.kind;
             if let ExprKind::Path(QPath::Resolved(None, path)) = path_expr.kind;
@@ -522,6 +640,7 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                     | crosspointer_transmute::check(cx, e, from_ty, to_ty)
                     | transmuting_null::check(cx, e, arg, to_ty)
                     | transmute_null_to_fn::check(cx, e, arg, to_ty)
+                    | transmute_fn_ptr_qualifiers::check(cx, e, from_ty, to_ty)
                     | transmute_ptr_to_ref::check(cx, e, from_ty, to_ty, arg, path, &self.msrv)
                     | transmute_int_to_char::check(cx, e, from_ty, to_ty, arg, const_context)
                     | transmute_ref_to_ref::check(cx, e, from_ty, to_ty, arg, const_context)
@@ -531,6 +650,7 @@ impl<'tcx> LateLintPass<'tcx> for Transmute {
                     | transmute_int_to_non_zero::check(cx, e, from_ty, to_ty, arg)
                     | transmute_float_to_int::check(cx, e, from_ty, to_ty, arg, const_context)
                     | transmute_num_to_bytes::check(cx, e, from_ty, to_ty, arg, const_context)
+                    | transmute_untrusted_int_to_enum::check(cx, e, from_ty, to_ty, arg)
                     | (
                         unsound_collection_transmute::check(cx, e, from_ty, to_ty)
                         || transmute_undefined_repr::check(cx, e, from_ty, to_ty)
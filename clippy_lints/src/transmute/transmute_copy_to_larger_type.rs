@@ -0,0 +1,28 @@
+use clippy_utils::diagnostics::span_lint_and_note;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+
+use super::TRANSMUTE_COPY_TO_LARGER_TYPE;
+
+/// Checks for `transmute_copy::<Src, Dst>` calls where `Dst` is larger than `Src`.
+pub(super) fn check(cx: &LateContext<'_>, e: &Expr<'_>, path_expr: &Expr<'_>) {
+    let mut types = cx.typeck_results().node_args(path_expr.hir_id).types();
+    if let (Some(from_ty), Some(to_ty)) = (types.next(), types.next())
+        && let Ok(from_layout) = cx.layout_of(from_ty)
+        && let Ok(to_layout) = cx.layout_of(to_ty)
+        && to_layout.size > from_layout.size
+    {
+        span_lint_and_note(
+            cx,
+            TRANSMUTE_COPY_TO_LARGER_TYPE,
+            e.span,
+            &format!(
+                "`transmute_copy` from `{from_ty}` ({} bytes) to the larger `{to_ty}` ({} bytes)",
+                from_layout.size.bytes(),
+                to_layout.size.bytes(),
+            ),
+            None,
+            "reading the destination would go past the end of the source, so this call always panics",
+        );
+    }
+}
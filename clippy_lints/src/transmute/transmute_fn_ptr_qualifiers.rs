@@ -0,0 +1,80 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_enclosing_block, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, Node, PatKind, Unsafety};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+
+use super::TRANSMUTE_FN_PTR_QUALIFIERS;
+
+/// Checks for `transmute` calls that remove `unsafe` from a function pointer or change its ABI,
+/// when the resulting function pointer is called.
+/// Returns `true` if it's triggered, otherwise returns `false`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, from_ty: Ty<'tcx>, to_ty: Ty<'tcx>) -> bool {
+    let (ty::FnPtr(from_sig), ty::FnPtr(to_sig)) = (from_ty.kind(), to_ty.kind()) else {
+        return false;
+    };
+    let removes_unsafe = from_sig.unsafety() == Unsafety::Unsafe && to_sig.unsafety() == Unsafety::Normal;
+    let changes_abi = from_sig.abi() != to_sig.abi();
+    if !(removes_unsafe || changes_abi) || !is_called(cx, e) {
+        return false;
+    }
+
+    span_lint_and_then(
+        cx,
+        TRANSMUTE_FN_PTR_QUALIFIERS,
+        e.span,
+        &format!("transmute from `{from_ty}` to `{to_ty}`, which is then called"),
+        |diag| {
+            if removes_unsafe {
+                diag.note("the `unsafe` qualifier is removed, so calling it doesn't require an `unsafe` block");
+            }
+            if changes_abi {
+                diag.note(format!(
+                    "the ABI changes from `{}` to `{}`, so it is called with the wrong calling convention",
+                    from_sig.abi().name(),
+                    to_sig.abi().name(),
+                ));
+            }
+            diag.help("call the function through its original type");
+        },
+    );
+    true
+}
+
+/// Checks if the function pointer produced by `e` is called, either directly or through the
+/// local it is bound to.
+fn is_called(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let mut child_id = e.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(e.hir_id) {
+        match node {
+            // Look through the `unsafe` block the transmute is usually wrapped in.
+            Node::Block(block) if block.expr.is_some_and(|tail| tail.hir_id == child_id) => {},
+            Node::Expr(Expr {
+                kind: ExprKind::Block(..),
+                ..
+            }) => {},
+            Node::Expr(Expr {
+                kind: ExprKind::Call(callee, _),
+                ..
+            }) => return callee.hir_id == child_id,
+            Node::Local(local) => {
+                return if let PatKind::Binding(_, local_id, ..) = local.pat.kind
+                    && let Some(block) = get_enclosing_block(cx, parent_id)
+                {
+                    for_each_expr(block, |e| match e.kind {
+                        ExprKind::Call(callee, _) if path_to_local_id(callee, local_id) => ControlFlow::Break(()),
+                        _ => ControlFlow::Continue(()),
+                    })
+                    .is_some()
+                } else {
+                    false
+                };
+            },
+            _ => return false,
+        }
+        child_id = parent_id;
+    }
+    false
+}
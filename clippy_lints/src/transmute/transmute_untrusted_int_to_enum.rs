@@ -0,0 +1,53 @@
+use crate::utils::untrusted_values::{is_checked_before, untrusted_origin, UntrustedOrigin};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::peel_hir_expr_refs;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+
+use super::TRANSMUTE_UNTRUSTED_INT_TO_ENUM;
+
+/// Checks for `transmute` calls turning an integer decoded from raw bytes into an enum.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'_>,
+    from_ty: Ty<'tcx>,
+    to_ty: Ty<'tcx>,
+    arg: &'tcx Expr<'_>,
+) -> bool {
+    if from_ty.is_integral()
+        && let ty::Adt(adt, _) = to_ty.kind()
+        && adt.is_enum()
+        && let Some((origin, UntrustedOrigin::Decoded)) = untrusted_origin(cx, arg)
+        && let Some(body_id) = cx.enclosing_body
+        && let body = cx.tcx.hir().body(body_id).value
+        && !is_checked_before(cx, body, arg, e, None)
+        && !is_checked_before(cx, body, origin, e, None)
+    {
+        span_lint_and_then(
+            cx,
+            TRANSMUTE_UNTRUSTED_INT_TO_ENUM,
+            e.span,
+            &format!("transmuting an integer decoded from raw bytes into the enum `{to_ty}`"),
+            |diag| {
+                diag.span_note(origin.span, "the integer is decoded from raw bytes here");
+                diag.note("any value that isn't the discriminant of a variant is undefined behavior");
+                diag.help(format!(
+                    "implement `TryFrom<{from_ty}>` for `{to_ty}`, checking the value against each variant"
+                ));
+            },
+        );
+        true
+    } else {
+        false
+    }
+}
+
+/// Checks for `transmute_copy` calls reading an enum out of a reference to an integer decoded
+/// from raw bytes.
+pub(super) fn check_copy<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, path_expr: &Expr<'_>, arg: &'tcx Expr<'_>) {
+    let mut types = cx.typeck_results().node_args(path_expr.hir_id).types();
+    if let (Some(from_ty), Some(to_ty)) = (types.next(), types.next()) {
+        check(cx, e, from_ty, to_ty, peel_hir_expr_refs(arg).0);
+    }
+}
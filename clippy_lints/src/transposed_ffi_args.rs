@@ -0,0 +1,119 @@
+use crate::utils::fn_set::FnSet;
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{fn_def_id, peel_casts};
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to foreign functions taking a buffer and its length, such as `memset` or
+    /// `read`, where a pointer is cast to an integer to be passed as an integer parameter, or an
+    /// integer is cast to a pointer to be passed as a pointer parameter, as well as calls to
+    /// `memset` with a length of `0` and a non-zero value.
+    ///
+    /// The functions are configured with the `ffi-memory-functions` option.
+    ///
+    /// ### Why is this bad?
+    /// The casts are usually needed because the arguments are in the wrong order, e.g. the
+    /// buffer and the length of `read(fd, len, buf)`, and the compiler only accepted the call
+    /// once they were added. `memset(dst, len, 0)` compiles without any cast and sets nothing.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// libc::read(fd, buf.len() as *mut c_void, buf.as_mut_ptr() as usize);
+    /// libc::memset(buf.as_mut_ptr().cast(), buf.len() as c_int, 0);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// libc::read(fd, buf.as_mut_ptr().cast(), buf.len());
+    /// libc::memset(buf.as_mut_ptr().cast(), 0, buf.len());
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub TRANSPOSED_FFI_ARGS,
+    suspicious,
+    "passing the arguments of a foreign function in the wrong order"
+}
+
+#[derive(Clone, Debug)]
+pub struct TransposedFfiArgs {
+    functions: Vec<String>,
+    fns: FnSet,
+}
+
+impl TransposedFfiArgs {
+    pub fn new(functions: Vec<String>) -> Self {
+        Self {
+            functions,
+            fns: FnSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(TransposedFfiArgs => [TRANSPOSED_FFI_ARGS]);
+
+impl<'tcx> LateLintPass<'tcx> for TransposedFfiArgs {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        self.fns.extend(cx, &self.functions);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, args) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(def_id) = fn_def_id(cx, expr)
+            && cx.tcx.is_foreign_item(def_id)
+            && self.fns.contains_callee(cx, expr)
+        {
+            let name = cx.tcx.item_name(def_id);
+            let inputs = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder().inputs();
+            let param_names = cx.tcx.fn_arg_names(def_id);
+            let mut mismatches: Vec<(Span, String)> = Vec::new();
+            for (i, (arg, param_ty)) in args.iter().zip(inputs).enumerate() {
+                let uncast = peel_casts(arg);
+                if uncast.hir_id == arg.hir_id {
+                    continue;
+                }
+                let uncast_ty = cx.typeck_results().expr_ty(uncast);
+                let param = param_names.get(i).map_or_else(|| format!("#{i}"), ToString::to_string);
+                if param_ty.is_integral() && uncast_ty.is_any_ptr() {
+                    mismatches.push((
+                        arg.span,
+                        format!("a pointer is cast to the integer parameter `{param}`"),
+                    ));
+                } else if param_ty.is_unsafe_ptr()
+                    && uncast_ty.is_integral()
+                    && !matches!(constant(cx, cx.typeck_results(), uncast), Some(Constant::Int(0)))
+                {
+                    mismatches.push((
+                        arg.span,
+                        format!("an integer is cast to the pointer parameter `{param}`"),
+                    ));
+                }
+            }
+            if name.as_str() == "memset"
+                && let [_, value, len] = args
+                && matches!(constant(cx, cx.typeck_results(), len), Some(Constant::Int(0)))
+                && !matches!(constant(cx, cx.typeck_results(), value), Some(Constant::Int(0)))
+            {
+                mismatches.push((len.span, "the length is `0`, so nothing is set".to_owned()));
+            }
+            if !mismatches.is_empty() {
+                span_lint_and_then(
+                    cx,
+                    TRANSPOSED_FFI_ARGS,
+                    expr.span,
+                    &format!("the arguments of `{name}` look transposed"),
+                    |diag| {
+                        for (span, note) in mismatches {
+                            diag.span_note(span, note);
+                        }
+                        diag.help("check the order of the arguments against the declaration of the function");
+                    },
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::utils::untrusted_values::is_checked_before;
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{eq_expr_value, expr_or_init};
+use rustc_hir::def::DefKind;
+use rustc_hir::{BinOpKind, Expr, ExprKind, Unsafety};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for indexing a slice, array or `Vec` with a non-constant index that is not
+    /// bounds-checked beforehand, inside `unsafe` or `extern` functions.
+    ///
+    /// An index counts as checked if it is compared with the length of the indexed value, or
+    /// passed to its `get` method, earlier in the function. `slice[slice.len() - 1]` is allowed
+    /// as well.
+    ///
+    /// With the `unchecked-indexing-crate-wide` option, all functions are checked.
+    ///
+    /// ### Why is this bad?
+    /// Out of bounds indexing panics, and a panic unwinding out of an `extern` function is
+    /// undefined behavior or aborts the process. Some coding guidelines for safety-critical
+    /// code forbid unchecked indexing altogether.
+    ///
+    /// ### Known problems
+    /// The comparison only has to come before the indexing, not to guard it, so
+    /// `if n < v.len() {} v[n]` isn't linted.
+    ///
+    /// ### Example
+    /// ```rust
+    /// unsafe extern "C" fn nth(values: *const u32, len: usize, n: usize) -> u32 {
+    ///     let values = std::slice::from_raw_parts(values, len);
+    ///     values[n]
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// unsafe extern "C" fn nth(values: *const u32, len: usize, n: usize) -> u32 {
+    ///     let values = std::slice::from_raw_parts(values, len);
+    ///     values.get(n).copied().unwrap_or_default()
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub UNCHECKED_INDEXING,
+    restriction,
+    "indexing with an index that isn't bounds-checked in `unsafe` or `extern` functions"
+}
+
+#[derive(Clone, Copy)]
+pub struct UncheckedIndexing {
+    crate_wide: bool,
+}
+
+impl UncheckedIndexing {
+    pub fn new(crate_wide: bool) -> Self {
+        Self { crate_wide }
+    }
+}
+
+impl_lint_pass!(UncheckedIndexing => [UNCHECKED_INDEXING]);
+
+impl<'tcx> LateLintPass<'tcx> for UncheckedIndexing {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Index(base, index, _) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(body_id) = cx.enclosing_body
+            && (self.crate_wide || in_unsafe_or_extern_fn(cx))
+            && is_indexable(cx, base)
+            && matches!(cx.typeck_results().expr_ty(index).kind(), ty::Uint(ty::UintTy::Usize))
+            && constant(cx, cx.typeck_results(), index).is_none()
+            && !is_last_index_of(cx, base, index)
+            && !is_checked_before(cx, cx.tcx.hir().body(body_id).value, index, expr, Some(base))
+        {
+            span_lint_and_help(
+                cx,
+                UNCHECKED_INDEXING,
+                expr.span,
+                "indexing with an index that isn't bounds-checked",
+                None,
+                "consider using `.get(..)` and handling the `None` case",
+            );
+        }
+    }
+}
+
+fn in_unsafe_or_extern_fn(cx: &LateContext<'_>) -> bool {
+    let Some(body_id) = cx.enclosing_body else {
+        return false;
+    };
+    // Closures inherit the check from the function they are defined in.
+    let fn_id = cx
+        .tcx
+        .typeck_root_def_id(cx.tcx.hir().body_owner_def_id(body_id).to_def_id());
+    if !matches!(cx.tcx.def_kind(fn_id), DefKind::Fn | DefKind::AssocFn) {
+        return false;
+    }
+    let sig = cx.tcx.fn_sig(fn_id).skip_binder();
+    sig.unsafety() == Unsafety::Unsafe || sig.abi() != Abi::Rust
+}
+
+fn is_indexable(cx: &LateContext<'_>, base: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(base).peel_refs();
+    matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::Vec)
+}
+
+/// Checks for `base[base.len() - 1]`, possibly through a local holding `base.len() - 1`.
+fn is_last_index_of(cx: &LateContext<'_>, base: &Expr<'_>, index: &Expr<'_>) -> bool {
+    if let ExprKind::Binary(op, lhs, _) = expr_or_init(cx, index).kind
+        && op.node == BinOpKind::Sub
+        && let ExprKind::MethodCall(method, receiver, [], _) = lhs.kind
+        && method.ident.name == sym::len
+    {
+        eq_expr_value(cx, receiver, base)
+    } else {
+        false
+    }
+}
@@ -0,0 +1,120 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{
+    fn_def_id, get_enclosing_block, get_parent_expr, is_foreign_call, match_def_path, path_to_local_id, paths,
+    peel_casts,
+};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::{Expr, ExprKind, Local, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, AdtDef, TypeAndMut};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+use rustc_span::symbol::Symbol;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for fields of a struct allocated with `malloc`, `aligned_alloc` or
+    /// `std::alloc::alloc` and bound to a local, possibly after a cast, which are read through
+    /// the pointer before being written.
+    ///
+    /// The struct is considered initialized as soon as the pointer is used in any other way than
+    /// accessing a field or checking it for null, e.g. passed to a function.
+    ///
+    /// ### Why is this bad?
+    /// These functions don't initialize the memory they return. Writing some of the fields
+    /// leaves the others uninitialized, and reading them is undefined behavior.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let p = malloc(size_of::<Point>()) as *mut Point;
+    /// (*p).x = 1;
+    /// let sum = (*p).x + (*p).y;
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let p = malloc(size_of::<Point>()) as *mut Point;
+    /// p.write(Point { x: 1, y: 0 });
+    /// let sum = (*p).x + (*p).y;
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub UNINIT_ALLOC_FIELD_READ,
+    suspicious,
+    "reading a field of a freshly allocated struct before writing it"
+}
+declare_lint_pass!(UninitAllocFieldRead => [UNINIT_ALLOC_FIELD_READ]);
+
+impl<'tcx> LateLintPass<'tcx> for UninitAllocFieldRead {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
+        if let PatKind::Binding(_, ptr_id, _, None) = local.pat.kind
+            && let Some(init) = local.init
+            && !in_external_macro(cx.sess(), local.span)
+            && let alloc = peel_casts(init)
+            && let Some(name) = uninit_alloc_fn(cx, alloc)
+            && let ty::RawPtr(TypeAndMut { ty: pointee, .. }) = cx.typeck_results().expr_ty(init).kind()
+            && pointee.ty_adt_def().is_some_and(AdtDef::is_struct)
+            && let Some(block) = get_enclosing_block(cx, local.hir_id)
+        {
+            let mut written = FxHashSet::default();
+            let read = for_each_expr(block, |e| {
+                if e.span.lo() <= local.span.hi() {
+                    return ControlFlow::Continue(Descend::Yes);
+                }
+                if let ExprKind::Field(base, field) = e.kind
+                    && let ExprKind::Unary(UnOp::Deref, ptr) = base.kind
+                    && path_to_local_id(ptr, ptr_id)
+                {
+                    match get_parent_expr(cx, e).map(|parent| parent.kind) {
+                        // `(*p).field = ..` or `addr_of_mut!((*p).field)`
+                        Some(ExprKind::Assign(lhs, ..)) if lhs.hir_id == e.hir_id => {
+                            written.insert(field.name);
+                        },
+                        Some(ExprKind::AddrOf(..)) => {
+                            written.insert(field.name);
+                        },
+                        _ if !written.contains(&field.name) => return ControlFlow::Break(Some((e, field.name))),
+                        _ => {},
+                    }
+                    return ControlFlow::Continue(Descend::No);
+                }
+                if path_to_local_id(e, ptr_id)
+                    && !get_parent_expr(cx, e).is_some_and(|parent| {
+                        matches!(
+                            parent.kind,
+                            ExprKind::MethodCall(method, _, [], _) if method.ident.as_str() == "is_null"
+                        )
+                    })
+                {
+                    // the whole struct may be initialized from here on
+                    return ControlFlow::Break(None);
+                }
+                ControlFlow::Continue(Descend::Yes)
+            });
+            if let Some(Some((read, field))) = read {
+                span_lint_and_then(
+                    cx,
+                    UNINIT_ALLOC_FIELD_READ,
+                    read.span,
+                    &format!("reading the field `{field}` of a struct allocated with `{name}` before writing it"),
+                    |diag| {
+                        diag.span_note(alloc.span, "the memory is allocated here, uninitialized");
+                        diag.help("write the field first, or initialize the whole struct with `write`");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// If `expr` is a call to a function allocating uninitialized memory, i.e. `std::alloc::alloc`,
+/// or a foreign `malloc` or `aligned_alloc`, returns the name of the function.
+fn uninit_alloc_fn(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<Symbol> {
+    let def_id = fn_def_id(cx, expr)?;
+    if match_def_path(cx, def_id, &paths::ALLOC_ALLOC) {
+        return Some(sym::alloc);
+    }
+    let name = cx.tcx.item_name(def_id);
+    (is_foreign_call(cx, expr) && matches!(name.as_str(), "malloc" | "aligned_alloc")).then_some(name)
+}
@@ -0,0 +1,116 @@
+use crate::utils::byte_buffers::{byte_buffer_origin, is_nul_terminated_literal};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{
+    expr_or_init, fn_def_id, higher, is_integer_const, path_to_local, path_to_local_id, peel_casts, peel_hir_expr_refs,
+};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, TypeAndMut};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for pointers into a local `Vec<u8>` passed to a foreign function as a C string,
+    /// i.e. as a `*const c_char` argument, when no NUL byte was added to the buffer.
+    ///
+    /// The buffer is considered terminated if it was created by `vec![..]` or `to_vec()` with a
+    /// trailing zero, or if a zero was pushed to it or a NUL-terminated literal appended to it
+    /// before the call.
+    ///
+    /// ### Why is this bad?
+    /// The foreign function reads until it finds a NUL byte. Without one, it reads past the end of
+    /// the buffer, which is undefined behavior.
+    ///
+    /// ### Known problems
+    /// Functions taking a byte pointer along with a length are linted as well.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let mut name = Vec::new();
+    /// name.extend_from_slice(b"HOME");
+    /// let home = unsafe { libc::getenv(name.as_ptr().cast()) };
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let mut name = Vec::new();
+    /// name.extend_from_slice(b"HOME");
+    /// name.push(0);
+    /// let home = unsafe { libc::getenv(name.as_ptr().cast()) };
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub UNTERMINATED_VEC_AS_C_STRING,
+    nursery,
+    "passing a `Vec<u8>` without a NUL terminator to a foreign function expecting a C string"
+}
+declare_lint_pass!(UnterminatedVecAsCString => [UNTERMINATED_VEC_AS_C_STRING]);
+
+impl<'tcx> LateLintPass<'tcx> for UnterminatedVecAsCString {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(_, args) = expr.kind
+            && !expr.span.from_expansion()
+            && let Some(def_id) = fn_def_id(cx, expr)
+            && cx.tcx.is_foreign_item(def_id)
+        {
+            let inputs = cx.tcx.fn_sig(def_id).instantiate_identity().skip_binder().inputs();
+            for (arg, input) in args.iter().zip(inputs) {
+                if let ty::RawPtr(TypeAndMut { ty: pointee, .. }) = input.kind()
+                    && matches!(pointee.kind(), ty::Uint(ty::UintTy::U8) | ty::Int(ty::IntTy::I8))
+                    && let ExprKind::MethodCall(method, recv, [], _) =
+                        peel_casts(expr_or_init(cx, peel_casts(arg))).kind
+                    && matches!(method.ident.as_str(), "as_ptr" | "as_mut_ptr" | "cast")
+                    && let Some(recv) = byte_buffer_origin(cx, method.ident.as_str(), recv)
+                    && let Some(local) = path_to_local(peel_hir_expr_refs(recv).0)
+                    && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv).peel_refs(), sym::Vec)
+                    && !is_nul_terminated_vec(cx, local, recv, expr.span)
+                {
+                    span_lint_and_help(
+                        cx,
+                        UNTERMINATED_VEC_AS_C_STRING,
+                        arg.span,
+                        "passing a byte buffer that is not known to be NUL-terminated as a C string",
+                        None,
+                        "push a NUL byte to the buffer before the call, or build a `CString` instead",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks if the `Vec` bound to `local` was created with a trailing zero, or if a zero was
+/// appended to it before `before`.
+fn is_nul_terminated_vec(cx: &LateContext<'_>, local: HirId, recv: &Expr<'_>, before: Span) -> bool {
+    let init = expr_or_init(cx, peel_hir_expr_refs(recv).0);
+    let init_terminated = match higher::VecArgs::hir(cx, init) {
+        Some(higher::VecArgs::Vec(elems)) => elems.last().is_some_and(|last| is_integer_const(cx, last, 0)),
+        Some(higher::VecArgs::Repeat(elem, _)) => is_integer_const(cx, elem, 0),
+        None => matches!(
+            init.kind,
+            ExprKind::MethodCall(method, lit, [], _)
+                if matches!(method.ident.as_str(), "to_vec" | "to_owned" | "into") && is_nul_terminated_literal(lit)
+        ),
+    };
+    init_terminated
+        || cx.enclosing_body.is_some_and(|body_id| {
+            for_each_expr(cx.tcx.hir().body(body_id).value, |e| {
+                if e.span.lo() < before.lo()
+                    && let ExprKind::MethodCall(method, receiver, [arg], _) = e.kind
+                    && path_to_local_id(receiver, local)
+                    && match method.ident.as_str() {
+                        "push" => is_integer_const(cx, arg, 0),
+                        "extend_from_slice" | "extend" => is_nul_terminated_literal(arg),
+                        _ => false,
+                    }
+                {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_some()
+        })
+}
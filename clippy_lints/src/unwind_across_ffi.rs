@@ -0,0 +1,98 @@
+use crate::utils::panicking_exprs::panicking_exprs;
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, FnDecl};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions with a foreign ABI that doesn't allow unwinding, such as
+    /// `extern "C"`, whose body may panic through panicking macros, `unwrap`, `expect`,
+    /// indexing or integer arithmetic.
+    ///
+    /// ### Why is this bad?
+    /// Such functions are usually called from foreign code. Unwinding out of them into a
+    /// caller that doesn't expect it is undefined behavior.
+    ///
+    /// ### Known problems
+    /// Functions called from the body may panic as well. This is not checked.
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern "C" fn parse(input: *const u8, len: usize) -> u32 {
+    ///     let input = unsafe { std::slice::from_raw_parts(input, len) };
+    ///     std::str::from_utf8(input).unwrap().parse().unwrap()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// extern "C" fn parse(input: *const u8, len: usize) -> u32 {
+    ///     let input = unsafe { std::slice::from_raw_parts(input, len) };
+    ///     std::str::from_utf8(input)
+    ///         .ok()
+    ///         .and_then(|input| input.parse().ok())
+    ///         .unwrap_or(0)
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub UNWIND_ACROSS_FFI,
+    restriction,
+    "functions with a non-unwinding foreign ABI that may panic"
+}
+declare_lint_pass!(UnwindAcrossFfi => [UNWIND_ACROSS_FFI]);
+
+impl<'tcx> LateLintPass<'tcx> for UnwindAcrossFfi {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        _: LocalDefId,
+    ) {
+        if let Some(header) = kind.header()
+            && !can_unwind(header.abi)
+            && !in_external_macro(cx.sess(), span)
+        {
+            let panics = panicking_exprs(cx, body, true);
+            if !panics.is_empty() {
+                span_lint_and_then(
+                    cx,
+                    UNWIND_ACROSS_FFI,
+                    span,
+                    &format!("this `extern \"{}\"` function may panic", header.abi.name()),
+                    move |diag| {
+                        diag.span_note(panics, "a panic here would unwind across the FFI boundary");
+                        diag.help(
+                            "handle the errors without panicking, wrap the body in `std::panic::catch_unwind`, \
+                            or use an unwinding ABI such as `extern \"C-unwind\"`",
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks if unwinding out of a function with the given ABI is allowed.
+fn can_unwind(abi: Abi) -> bool {
+    !matches!(
+        abi,
+        Abi::C { unwind: false }
+            | Abi::Cdecl { unwind: false }
+            | Abi::Stdcall { unwind: false }
+            | Abi::Fastcall { unwind: false }
+            | Abi::Vectorcall { unwind: false }
+            | Abi::Thiscall { unwind: false }
+            | Abi::Aapcs { unwind: false }
+            | Abi::Win64 { unwind: false }
+            | Abi::SysV64 { unwind: false }
+            | Abi::System { unwind: false }
+    )
+}
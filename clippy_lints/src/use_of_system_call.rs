@@ -0,0 +1,85 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to functions running an external command, such as `libc::system` or
+    /// `libc::popen`, which go through the shell, or `libc::execlp`, which looks the program up
+    /// in `PATH`, whatever their arguments are.
+    ///
+    /// The functions are configured with the `shell-exec-functions` option.
+    ///
+    /// ### Why is this bad?
+    /// The shell interprets the whole command line, so any part of it that comes from outside
+    /// the program can inject further commands. `execlp` doesn't use a shell, but runs whatever
+    /// program is found first in `PATH`. Many hardening guidelines ban these functions outright
+    /// rather than auditing every call.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// unsafe { libc::system(c"ls -l /tmp".as_ptr()) };
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// std::process::Command::new("ls").args(["-l", "/tmp"]).status();
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub USE_OF_SYSTEM_CALL,
+    restriction,
+    "calling a function that runs an external command, e.g. through the shell"
+}
+
+#[derive(Clone, Debug)]
+pub struct UseOfSystemCall {
+    functions: Vec<String>,
+    def_ids: DefIdSet,
+}
+
+impl UseOfSystemCall {
+    pub fn new(functions: Vec<String>) -> Self {
+        Self {
+            functions,
+            def_ids: DefIdSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(UseOfSystemCall => [USE_OF_SYSTEM_CALL]);
+
+impl<'tcx> LateLintPass<'tcx> for UseOfSystemCall {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.functions {
+            let segs: Vec<_> = path.split("::").collect();
+            self.def_ids.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(..) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(def_id) = fn_def_id(cx, expr)
+            && self.def_ids.contains(&def_id)
+        {
+            let name = cx.tcx.item_name(def_id);
+            // Of the default functions, only `system` and `popen` go through the shell
+            let what = if matches!(name.as_str(), "system" | "popen") {
+                "a command through the shell"
+            } else {
+                "an external command"
+            };
+            span_lint_and_help(
+                cx,
+                USE_OF_SYSTEM_CALL,
+                expr.span,
+                &format!("calling `{name}` runs {what}"),
+                None,
+                "use `std::process::Command` with an explicit program and arguments instead",
+            );
+        }
+    }
+}
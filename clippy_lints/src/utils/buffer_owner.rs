@@ -0,0 +1,43 @@
+//! Lookup of the owner of a borrowed heap buffer, shared by the lints checking for pointers into
+//! a buffer that is dropped when they are returned.
+
+use clippy_utils::path_to_local;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+use rustc_span::sym;
+
+/// Returns the local or temporary `String`, `Vec`, `Box` or `CString` owning the buffer that
+/// `expr` borrows, going through methods borrowing their receiver, e.g. `s` in `s.as_bytes()`.
+pub fn buffer_owner<'a>(cx: &LateContext<'_>, mut expr: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    loop {
+        if is_owning_buffer(cx, cx.typeck_results().expr_ty(expr)) {
+            return (path_to_local(expr).is_some()
+                || matches!(expr.kind, ExprKind::Call(..) | ExprKind::MethodCall(..)))
+            .then_some(expr);
+        }
+        if let ExprKind::MethodCall(_, receiver, [], _) = expr.kind
+            && let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+            && cx
+                .tcx
+                .fn_sig(def_id)
+                .instantiate_identity()
+                .skip_binder()
+                .inputs()
+                .first()
+                .is_some_and(|ty| ty.is_ref())
+        {
+            expr = receiver;
+        } else {
+            return None;
+        }
+    }
+}
+
+fn is_owning_buffer(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    ty.is_box()
+        || is_type_diagnostic_item(cx, ty, sym::String)
+        || is_type_diagnostic_item(cx, ty, sym::Vec)
+        || is_type_diagnostic_item(cx, ty, sym::cstring_type)
+}
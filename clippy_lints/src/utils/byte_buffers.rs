@@ -0,0 +1,46 @@
+//! Detection of pointers into byte buffers and of NUL-terminated byte string literals, shared by
+//! the lints checking for byte buffers used as C strings.
+
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{expr_or_init, peel_casts, peel_hir_expr_refs};
+use rustc_ast::LitKind;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, IntTy, UintTy};
+use rustc_span::sym;
+
+/// Given the receiver `recv` of a call to `method`, one of `as_ptr`, `as_mut_ptr` or `cast`,
+/// follows `ptr.cast()` back to the `as_ptr` call and returns the buffer the pointer was taken
+/// from, if it is a byte slice, array or `Vec<u8>`.
+pub fn byte_buffer_origin<'tcx>(
+    cx: &LateContext<'tcx>,
+    method: &str,
+    recv: &'tcx Expr<'tcx>,
+) -> Option<&'tcx Expr<'tcx>> {
+    if method == "cast" {
+        return match expr_or_init(cx, peel_casts(recv)).kind {
+            ExprKind::MethodCall(method, recv, [], _) if method.ident.as_str() != "cast" => {
+                byte_buffer_origin(cx, method.ident.as_str(), recv)
+            },
+            _ => None,
+        };
+    }
+    let recv_ty = cx.typeck_results().expr_ty(recv).peel_refs();
+    let elem_ty = match recv_ty.kind() {
+        ty::Slice(elem) | ty::Array(elem, _) => *elem,
+        ty::Adt(_, args) if is_type_diagnostic_item(cx, recv_ty, sym::Vec) => args.type_at(0),
+        _ => return None,
+    };
+    matches!(elem_ty.kind(), ty::Uint(UintTy::U8) | ty::Int(IntTy::I8)).then_some(recv)
+}
+
+/// Checks if `expr` is a byte string literal ending with a NUL byte, possibly behind references.
+pub fn is_nul_terminated_literal(expr: &Expr<'_>) -> bool {
+    if let ExprKind::Lit(lit) = peel_hir_expr_refs(expr).0.kind
+        && let LitKind::ByteStr(bytes, _) = &lit.node
+    {
+        bytes.last() == Some(&0)
+    } else {
+        false
+    }
+}
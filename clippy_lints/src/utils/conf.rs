@@ -36,6 +36,110 @@ const DEFAULT_DOC_VALID_IDENTS: &[&str] = &[
 ];
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
 const DEFAULT_ALLOWED_IDENTS_BELOW_MIN_CHARS: &[&str] = &["i", "j", "x", "y", "z", "w", "n"];
+const DEFAULT_IGNORED_LIBC_RETURN_FUNCTIONS: &[&str] = &[
+    "libc::close",
+    "libc::fclose",
+    "libc::fflush",
+    "libc::fread",
+    "libc::fwrite",
+    "libc::read",
+    "libc::write",
+];
+
+const DEFAULT_READ_INTO_BUFFER_FUNCTIONS: &[&str] = &["libc::pread", "libc::read", "libc::recv", "libc::recvfrom"];
+
+const DEFAULT_INSECURE_CRYPTO_PATHS: &[&str] = &[
+    "des::Des",
+    "des::TdesEde2",
+    "des::TdesEde3",
+    "des::TdesEee2",
+    "des::TdesEee3",
+    "ecb::Decryptor",
+    "ecb::Encryptor",
+    "md5::compute",
+    "md5::Context",
+    "md5::Md5",
+    "rc4::Rc4",
+    "sha1::Sha1",
+];
+
+const DEFAULT_NON_CRYPTO_RNG_PATHS: &[&str] = &[
+    "rand::random",
+    "rand::rngs::SmallRng",
+    "rand::rngs::ThreadRng",
+    "rand::thread_rng",
+];
+
+const DEFAULT_FS_TOCTOU_CHECK_FUNCTIONS: &[&str] = &[
+    "std::fs::metadata",
+    "std::fs::symlink_metadata",
+    "std::path::Path::exists",
+    "std::path::Path::is_dir",
+    "std::path::Path::is_file",
+    "std::path::Path::metadata",
+    "std::path::Path::symlink_metadata",
+    "std::path::Path::try_exists",
+];
+
+const DEFAULT_FS_TOCTOU_USE_FUNCTIONS: &[&str] = &[
+    "std::fs::copy",
+    "std::fs::create_dir",
+    "std::fs::create_dir_all",
+    "std::fs::File::create",
+    "std::fs::File::open",
+    "std::fs::OpenOptions::open",
+    "std::fs::read",
+    "std::fs::read_to_string",
+    "std::fs::remove_dir",
+    "std::fs::remove_dir_all",
+    "std::fs::remove_file",
+    "std::fs::rename",
+    "std::fs::set_permissions",
+    "std::fs::write",
+];
+
+const DEFAULT_SECRET_NAME_PATTERNS: &[&str] = &["iv", "key", "nonce", "password", "salt", "secret", "token"];
+
+const DEFAULT_OWNING_RESOURCE_TYPES: &[&str] = &[
+    "std::fs::File",
+    "std::net::TcpListener",
+    "std::net::TcpStream",
+    "std::net::UdpSocket",
+    "std::os::fd::OwnedFd",
+    "std::process::Child",
+    "std::sync::MutexGuard",
+    "std::sync::RwLockReadGuard",
+    "std::sync::RwLockWriteGuard",
+];
+
+const DEFAULT_ENV_MUTATING_FUNCTIONS: &[&str] = &["libc::clearenv", "libc::putenv", "libc::setenv", "libc::unsetenv"];
+
+const DEFAULT_SHELL_EXEC_FUNCTIONS: &[&str] = &["libc::execlp", "libc::popen", "libc::system"];
+
+const DEFAULT_ERRNO_SETTING_FUNCTIONS: &[&str] = &[
+    "libc::fopen",
+    "libc::open",
+    "libc::read",
+    "libc::socket",
+    "libc::write",
+];
+
+const DEFAULT_RESOURCE_FUNCTION_PAIRS: &[(&str, &str)] = &[
+    ("libc::pthread_cond_init", "libc::pthread_cond_destroy"),
+    ("libc::pthread_mutex_init", "libc::pthread_mutex_destroy"),
+    ("libc::pthread_rwlock_init", "libc::pthread_rwlock_destroy"),
+    ("libc::sem_init", "libc::sem_destroy"),
+];
+
+const DEFAULT_SLEEP_FUNCTIONS: &[&str] = &["std::thread::sleep", "tokio::time::sleep"];
+
+const DEFAULT_FFI_MEMORY_FUNCTIONS: &[&str] = &[
+    "libc::memcpy",
+    "libc::memmove",
+    "libc::memset",
+    "libc::read",
+    "libc::write",
+];
 
 /// Holds information used by `MISSING_ENFORCED_IMPORT_RENAMES` lint.
 #[derive(Clone, Debug, Deserialize)]
@@ -68,6 +172,80 @@ impl DisallowedPath {
     }
 }
 
+/// Holds information used by `STACK_PTR_ESCAPES_VIA_CALL` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RetainingFunction {
+    Simple(String),
+    WithArgs { path: String, args: Vec<usize> },
+}
+
+impl RetainingFunction {
+    pub fn path(&self) -> &str {
+        let (Self::Simple(path) | Self::WithArgs { path, .. }) = self;
+
+        path
+    }
+
+    /// Whether the function retains the pointer passed as its `index`th parameter, counting
+    /// `self` as the first parameter of a method.
+    pub fn retains_arg(&self, index: usize) -> bool {
+        match self {
+            Self::Simple(_) => true,
+            Self::WithArgs { args, .. } => args.contains(&index),
+        }
+    }
+}
+
+/// Holds information used by `MISSING_ERRNO_CHECK` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ErrnoSettingFunction {
+    Simple(String),
+    WithFailure { path: String, failure: i64 },
+}
+
+impl ErrnoSettingFunction {
+    pub fn path(&self) -> &str {
+        let (Self::Simple(path) | Self::WithFailure { path, .. }) = self;
+
+        path
+    }
+
+    /// The value returned on failure, if it was configured.
+    pub fn failure(&self) -> Option<i64> {
+        match self {
+            Self::Simple(_) => None,
+            Self::WithFailure { failure, .. } => Some(*failure),
+        }
+    }
+}
+
+/// Holds information used by `HARDCODED_ADDRESS_DEREF` lint.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AddressRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl AddressRange {
+    /// Whether `address` is in `start..end`.
+    pub fn contains(&self, address: u128) -> bool {
+        u128::from(self.start) <= address && address < u128::from(self.end)
+    }
+}
+
+/// Holds information used by `MISMATCHED_RESOURCE_RELEASE` lint.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResourcePair {
+    pub acquire: String,
+    pub release: String,
+    #[serde(default)]
+    pub acquire_arg: usize,
+    #[serde(default)]
+    pub release_arg: usize,
+}
+
 /// Conf with parse errors
 #[derive(Default)]
 pub struct TryConf {
@@ -581,6 +759,144 @@ define_Conf! {
     /// for _ in &mut *rmvec {}
     /// ```
     (enforce_iter_loop_reborrow: bool = false),
+    /// Lint: STACK_PTR_ESCAPES_VIA_CALL, BORROWING_CLOSURE_CALLBACK, TEMPORARY_PTR_ESCAPES_VIA_CALL.
+    ///
+    /// The list of functions that retain a pointer argument beyond the call, written as fully qualified paths.
+    /// By default every argument is assumed to be retained. To only check some of them, list their zero-based
+    /// indices, e.g. `{ path = "ffi::set_callback", args = [1] }`, where `self` is the first argument of a method.
+    (retaining_functions: Vec<crate::utils::conf::RetainingFunction> = Vec::new()),
+    /// Lint: IGNORED_LIBC_RETURN.
+    ///
+    /// The list of C functions whose return value must be checked, written as fully qualified paths.
+    /// `extern` declarations with the same name as one of these functions are checked as well. The value
+    /// `".."` can be used as part of the list to indicate that the configured values should be appended to the
+    /// default configuration of Clippy. By default, any configuration will replace the default value.
+    (ignored_libc_return_functions: Vec<String> = super::DEFAULT_IGNORED_LIBC_RETURN_FUNCTIONS.iter().map(ToString::to_string).collect()),
+    /// Lint: IGNORED_READ_LENGTH.
+    ///
+    /// The list of C functions reading into a buffer, written as fully qualified paths. They must take the
+    /// buffer as their second argument and its capacity as their third. `extern` declarations with the same
+    /// name as one of these functions are checked as well. The value `".."` can be used as part of the list to
+    /// indicate that the configured values should be appended to the default configuration of Clippy. By
+    /// default, any configuration will replace the default value.
+    (read_into_buffer_functions: Vec<String> =
+        super::DEFAULT_READ_INTO_BUFFER_FUNCTIONS.iter().map(ToString::to_string).collect()),
+    /// Lint: UNCHECKED_INDEXING.
+    ///
+    /// Whether to check indexing in all functions instead of only `unsafe` and `extern` ones.
+    (unchecked_indexing_crate_wide: bool = false),
+    /// Lint: INSECURE_CRYPTO.
+    ///
+    /// The list of functions and types implementing broken cryptographic primitives, written as fully qualified
+    /// paths. The value `".."` can be used as part of the list to indicate that the configured values should be
+    /// appended to the default configuration of Clippy. By default, any configuration will replace the default value.
+    (insecure_crypto_paths: Vec<String> = super::DEFAULT_INSECURE_CRYPTO_PATHS.iter().map(ToString::to_string).collect()),
+    /// Lint: NON_CRYPTO_RNG_FOR_SECRET.
+    ///
+    /// The list of random number generators that aren't cryptographically secure, and of functions returning
+    /// their output, written as fully qualified paths. The value `".."` can be used as part of the list to
+    /// indicate that the configured values should be appended to the default configuration of Clippy. By
+    /// default, any configuration will replace the default value.
+    (non_crypto_rng_paths: Vec<String> = super::DEFAULT_NON_CRYPTO_RNG_PATHS.iter().map(ToString::to_string).collect()),
+    /// Lint: NON_CRYPTO_RNG_FOR_SECRET.
+    ///
+    /// The words which, when part of the name of a binding or field, mark it as holding secret material.
+    /// The value `".."` can be used as part of the list to indicate that the configured values should be
+    /// appended to the default configuration of Clippy. By default, any configuration will replace the
+    /// default value.
+    (secret_name_patterns: Vec<String> = super::DEFAULT_SECRET_NAME_PATTERNS.iter().map(ToString::to_string).collect()),
+    /// Lint: FS_TOCTOU.
+    ///
+    /// The list of functions checking a filesystem path, written as fully qualified paths. The value `".."` can
+    /// be used as part of the list to indicate that the configured values should be appended to the default
+    /// configuration of Clippy. By default, any configuration will replace the default value.
+    (fs_toctou_check_functions: Vec<String> =
+        super::DEFAULT_FS_TOCTOU_CHECK_FUNCTIONS.iter().map(ToString::to_string).collect()),
+    /// Lint: FS_TOCTOU.
+    ///
+    /// The list of functions operating on a filesystem path, written as fully qualified paths. The value `".."`
+    /// can be used as part of the list to indicate that the configured values should be appended to the default
+    /// configuration of Clippy. By default, any configuration will replace the default value.
+    (fs_toctou_use_functions: Vec<String> =
+        super::DEFAULT_FS_TOCTOU_USE_FUNCTIONS.iter().map(ToString::to_string).collect()),
+    /// Lint: FORGET_OWNING_RESOURCE.
+    ///
+    /// The list of types owning an OS resource or a lock, written as fully qualified paths. The value `".."` can
+    /// be used as part of the list to indicate that the configured values should be appended to the default
+    /// configuration of Clippy. By default, any configuration will replace the default value.
+    (owning_resource_types: Vec<String> =
+        super::DEFAULT_OWNING_RESOURCE_TYPES.iter().map(ToString::to_string).collect()),
+    /// Lint: RACY_ENV_MUTATION.
+    ///
+    /// The list of functions modifying the process environment, written as fully qualified paths. The value
+    /// `".."` can be used as part of the list to indicate that the configured values should be appended to the
+    /// default configuration of Clippy. By default, any configuration will replace the default value.
+    (env_mutating_functions: Vec<String> =
+        super::DEFAULT_ENV_MUTATING_FUNCTIONS.iter().map(ToString::to_string).collect()),
+    /// Lint: USE_OF_SYSTEM_CALL.
+    ///
+    /// The list of functions running an external command, written as fully qualified paths. The value
+    /// `".."` can be used as part of the list to indicate that the configured values should be appended to the
+    /// default configuration of Clippy. By default, any configuration will replace the default value.
+    (shell_exec_functions: Vec<String> =
+        super::DEFAULT_SHELL_EXEC_FUNCTIONS.iter().map(ToString::to_string).collect()),
+    /// Lint: MISSING_ERRNO_CHECK.
+    ///
+    /// The list of functions reporting their errors through `errno`, written as fully qualified paths. Their
+    /// return value is expected to be compared to null for functions returning a pointer, and to `-1` otherwise.
+    /// A different value can be given with e.g. `{ path = "ffi::sem_open", failure = 0 }`. `extern` declarations
+    /// with the same name as one of these functions are checked as well.
+    (errno_setting_functions: Vec<crate::utils::conf::ErrnoSettingFunction> = super::DEFAULT_ERRNO_SETTING_FUNCTIONS
+        .iter()
+        .map(|&path| crate::utils::conf::ErrnoSettingFunction::Simple(path.to_owned()))
+        .collect()),
+    /// Lint: HARDCODED_ADDRESS_DEREF.
+    ///
+    /// The address ranges of memory-mapped registers, which may be dereferenced through a pointer cast from an
+    /// integer, e.g. `{ start = 0x4000_0000, end = 0x5000_0000 }`. The `end` address is excluded.
+    (mmio_address_ranges: Vec<crate::utils::conf::AddressRange> = Vec::new()),
+    /// Lint: MISMATCHED_RESOURCE_RELEASE.
+    ///
+    /// The pairs of functions acquiring and releasing a resource through a handle, written as fully qualified
+    /// paths, e.g. `{ acquire = "ffi::lock_init", release = "ffi::lock_destroy" }`. The handle is taken to be the
+    /// first argument of both functions, other positions can be given with `acquire_arg` and `release_arg`,
+    /// counting from `0`. The configured pairs replace the default `libc` ones, which have to be listed again
+    /// to keep them checked.
+    (resource_function_pairs: Vec<crate::utils::conf::ResourcePair> = super::DEFAULT_RESOURCE_FUNCTION_PAIRS
+        .iter()
+        .map(|&(acquire, release)| crate::utils::conf::ResourcePair {
+            acquire: acquire.to_owned(),
+            release: release.to_owned(),
+            acquire_arg: 0,
+            release_arg: 0,
+        })
+        .collect()),
+    /// Lint: BLOCKING_OP_IN_ASYNC.
+    ///
+    /// Whether to allow blocking operations on the standard streams, such as `Stdin::read_line`, inside `async`
+    /// bodies.
+    (allow_io_blocking_ops: bool = false),
+    /// Lint: FFI_UNSAFE_PARAM_TYPE.
+    ///
+    /// The types accepted in the signatures of `extern` functions, written as fully qualified paths, e.g.
+    /// `std::string::String` if the foreign code only passes it back to Rust without looking into it.
+    (ffi_safe_types: Vec<String> = Vec::new()),
+    /// Lint: SLEEP_WITH_UNTRUSTED_DURATION.
+    ///
+    /// The list of functions putting the current thread or task to sleep for a `Duration`, written as fully
+    /// qualified paths. The value `".."` can be used as part of the list to indicate that the configured values
+    /// should be appended to the default configuration of Clippy. By default, any configuration will replace the
+    /// default value.
+    (sleep_functions: Vec<String> =
+        super::DEFAULT_SLEEP_FUNCTIONS.iter().map(ToString::to_string).collect()),
+    /// Lint: TRANSPOSED_FFI_ARGS.
+    ///
+    /// The list of foreign functions taking a buffer and its length whose arguments are checked, written as
+    /// fully qualified paths. `extern` declarations with the same name are checked as well. The value `".."` can
+    /// be used as part of the list to indicate that the configured values should be appended to the default
+    /// configuration of Clippy. By default, any configuration will replace the default value.
+    (ffi_memory_functions: Vec<String> =
+        super::DEFAULT_FFI_MEMORY_FUNCTIONS.iter().map(ToString::to_string).collect()),
 }
 
 /// Search for the configuration file.
@@ -648,6 +964,27 @@ pub fn read(sess: &Session, path: &Path) -> TryConf {
         Ok(mut conf) => {
             extend_vec_if_indicator_present(&mut conf.conf.doc_valid_idents, DEFAULT_DOC_VALID_IDENTS);
             extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
+            extend_vec_if_indicator_present(
+                &mut conf.conf.ignored_libc_return_functions,
+                DEFAULT_IGNORED_LIBC_RETURN_FUNCTIONS,
+            );
+            extend_vec_if_indicator_present(
+                &mut conf.conf.read_into_buffer_functions,
+                DEFAULT_READ_INTO_BUFFER_FUNCTIONS,
+            );
+            extend_vec_if_indicator_present(&mut conf.conf.insecure_crypto_paths, DEFAULT_INSECURE_CRYPTO_PATHS);
+            extend_vec_if_indicator_present(&mut conf.conf.non_crypto_rng_paths, DEFAULT_NON_CRYPTO_RNG_PATHS);
+            extend_vec_if_indicator_present(&mut conf.conf.secret_name_patterns, DEFAULT_SECRET_NAME_PATTERNS);
+            extend_vec_if_indicator_present(
+                &mut conf.conf.fs_toctou_check_functions,
+                DEFAULT_FS_TOCTOU_CHECK_FUNCTIONS,
+            );
+            extend_vec_if_indicator_present(&mut conf.conf.fs_toctou_use_functions, DEFAULT_FS_TOCTOU_USE_FUNCTIONS);
+            extend_vec_if_indicator_present(&mut conf.conf.owning_resource_types, DEFAULT_OWNING_RESOURCE_TYPES);
+            extend_vec_if_indicator_present(&mut conf.conf.env_mutating_functions, DEFAULT_ENV_MUTATING_FUNCTIONS);
+            extend_vec_if_indicator_present(&mut conf.conf.shell_exec_functions, DEFAULT_SHELL_EXEC_FUNCTIONS);
+            extend_vec_if_indicator_present(&mut conf.conf.sleep_functions, DEFAULT_SLEEP_FUNCTIONS);
+            extend_vec_if_indicator_present(&mut conf.conf.ffi_memory_functions, DEFAULT_FFI_MEMORY_FUNCTIONS);
             // TODO: THIS SHOULD BE TESTED, this comment will be gone soon
             if conf.conf.allowed_idents_below_min_chars.contains(&"..".to_owned()) {
                 conf.conf
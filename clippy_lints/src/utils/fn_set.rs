@@ -0,0 +1,40 @@
+//! A set of functions configured by path, shared by the lints checking calls to C functions.
+
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::Expr;
+use rustc_lint::LateContext;
+use rustc_span::Symbol;
+
+/// Functions given as fully qualified paths. `extern` declarations with the same name as one of
+/// them are part of the set as well.
+#[derive(Clone, Debug, Default)]
+pub struct FnSet {
+    def_ids: DefIdSet,
+    names: FxHashSet<Symbol>,
+}
+
+impl FnSet {
+    pub fn extend(&mut self, cx: &LateContext<'_>, paths: &[String]) {
+        for path in paths {
+            self.insert(cx, path);
+        }
+    }
+
+    /// Adds the function at `path`, returning its name.
+    pub fn insert(&mut self, cx: &LateContext<'_>, path: &str) -> Option<Symbol> {
+        let segs: Vec<_> = path.split("::").collect();
+        self.def_ids.extend(def_path_def_ids(cx, &segs));
+        let name = Symbol::intern(segs.last()?);
+        self.names.insert(name);
+        Some(name)
+    }
+
+    pub fn contains_callee(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+        fn_def_id(cx, expr).is_some_and(|def_id| {
+            self.def_ids.contains(&def_id)
+                || (cx.tcx.is_foreign_item(def_id) && self.names.contains(&cx.tcx.item_name(def_id)))
+        })
+    }
+}
@@ -1,9 +1,15 @@
 pub mod author;
+pub mod buffer_owner;
+pub mod byte_buffers;
 pub mod conf;
 pub mod dump_hir;
+pub mod fn_set;
 pub mod format_args_collector;
 #[cfg(feature = "internal")]
 pub mod internal_lints;
+pub mod panicking_exprs;
+pub mod retaining_functions;
+pub mod untrusted_values;
 #[cfg(feature = "internal")]
 use itertools::Itertools;
 
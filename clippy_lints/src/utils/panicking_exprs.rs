@@ -0,0 +1,96 @@
+//! Detection of the expressions that may panic, shared by the lints checking for panics where
+//! unwinding is a problem.
+
+use clippy_utils::consts::constant;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr_with_closures, Descend};
+use clippy_utils::{fn_def_id, get_parent_node, match_def_path, paths};
+use core::ops::ControlFlow;
+use rustc_hir as hir;
+use rustc_hir::{BinOpKind, ExprKind, Node, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+/// Collects the spans of the expressions in `body` that may panic: panicking macros and
+/// assertions, `unwrap` and `expect` on `Option` and `Result`, and indexing. If `arithmetic` is
+/// set, integer arithmetic that may overflow or divide by zero is included as well. Closures that
+/// are stored or passed to `catch_unwind` are skipped.
+pub fn panicking_exprs<'tcx>(cx: &LateContext<'tcx>, body: &'tcx hir::Body<'tcx>, arithmetic: bool) -> Vec<Span> {
+    let mut panics = Vec::new();
+    let _: Option<!> = for_each_expr_with_closures(cx, body.value, |e| {
+        if let Some(macro_call) = root_macro_call_first_node(cx, e) {
+            if matches!(
+                cx.tcx.item_name(macro_call.def_id).as_str(),
+                "panic" | "assert" | "assert_eq" | "assert_ne" | "todo" | "unimplemented" | "unreachable"
+            ) {
+                panics.push(macro_call.span);
+                return ControlFlow::Continue(Descend::No);
+            }
+        }
+        if e.span.from_expansion() {
+            return ControlFlow::Continue(Descend::Yes);
+        }
+        let typeck = cx.typeck_results();
+        match e.kind {
+            ExprKind::Closure(_) if is_stored(cx, e) || is_caught(cx, e) => return ControlFlow::Continue(Descend::No),
+            ExprKind::MethodCall(method, receiver, ..) if matches!(method.ident.as_str(), "unwrap" | "expect") => {
+                let receiver_ty = typeck.expr_ty(receiver).peel_refs();
+                if is_type_diagnostic_item(cx, receiver_ty, sym::Option)
+                    || is_type_diagnostic_item(cx, receiver_ty, sym::Result)
+                {
+                    panics.push(e.span);
+                }
+            },
+            ExprKind::Index(array, index, _) => {
+                // Let rustc's `unconditional_panic` lint handle constant indexing on arrays.
+                let is_array = matches!(typeck.expr_ty(array).peel_refs().kind(), ty::Array(..));
+                if !is_array || constant(cx, typeck, index).is_none() {
+                    panics.push(e.span);
+                }
+            },
+            ExprKind::Binary(op, lhs, rhs) | ExprKind::AssignOp(op, lhs, rhs)
+                if arithmetic
+                    && matches!(
+                        op.node,
+                        BinOpKind::Add | BinOpKind::Sub | BinOpKind::Mul | BinOpKind::Div | BinOpKind::Rem
+                    )
+                    && typeck.expr_ty(lhs).peel_refs().is_integral()
+                    && (constant(cx, typeck, lhs).is_none() || constant(cx, typeck, rhs).is_none()) =>
+            {
+                panics.push(e.span);
+            },
+            ExprKind::Unary(UnOp::Neg, operand)
+                if arithmetic
+                    && typeck.expr_ty(operand).peel_refs().is_signed()
+                    && constant(cx, typeck, operand).is_none() =>
+            {
+                panics.push(e.span);
+            },
+            _ => {},
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+    panics
+}
+
+/// Checks if the closure `e` is passed to `catch_unwind`, possibly wrapped in `AssertUnwindSafe`.
+fn is_caught(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> bool {
+    cx.tcx.hir().parent_iter(e.hir_id).take(2).any(|(_, node)| {
+        if let Node::Expr(call) = node {
+            fn_def_id(cx, call).is_some_and(|def_id| match_def_path(cx, def_id, &paths::CATCH_UNWIND))
+        } else {
+            false
+        }
+    })
+}
+
+/// Checks if the closure `e` is stored instead of being called or passed on.
+fn is_stored(cx: &LateContext<'_>, e: &hir::Expr<'_>) -> bool {
+    match get_parent_node(cx.tcx, e.hir_id) {
+        Some(Node::Local(_) | Node::ExprField(_)) => true,
+        Some(Node::Expr(parent)) => matches!(parent.kind, ExprKind::Assign(_, value, _) if value.hir_id == e.hir_id),
+        _ => false,
+    }
+}
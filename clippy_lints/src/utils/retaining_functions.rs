@@ -0,0 +1,53 @@
+//! Resolution of the `retaining-functions` configuration, shared by the lints checking what is
+//! passed to these functions.
+
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use std::iter;
+
+use crate::utils::conf::RetainingFunction;
+
+#[derive(Clone, Debug)]
+pub struct RetainingFunctions {
+    conf: Vec<RetainingFunction>,
+    def_ids: DefIdMap<usize>,
+}
+
+impl RetainingFunctions {
+    pub fn new(conf: Vec<RetainingFunction>) -> Self {
+        Self {
+            conf,
+            def_ids: DefIdMap::default(),
+        }
+    }
+
+    /// Resolves the configured paths, to be called from `check_crate`.
+    pub fn resolve(&mut self, cx: &LateContext<'_>) {
+        for (index, conf) in self.conf.iter().enumerate() {
+            let segs: Vec<_> = conf.path().split("::").collect();
+            for id in def_path_def_ids(cx, &segs) {
+                self.def_ids.insert(id, index);
+            }
+        }
+    }
+
+    /// If `expr` calls one of the configured functions, returns the arguments it retains. The
+    /// receiver of a method call counts as its first argument.
+    pub fn retained_args<'a>(&self, cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Vec<&'a Expr<'a>> {
+        let args: Vec<_> = match expr.kind {
+            ExprKind::Call(_, args) => args.iter().collect(),
+            ExprKind::MethodCall(_, receiver, args, _) => iter::once(receiver).chain(args).collect(),
+            _ => return Vec::new(),
+        };
+        let Some(&index) = fn_def_id(cx, expr).and_then(|def_id| self.def_ids.get(&def_id)) else {
+            return Vec::new();
+        };
+        let conf = &self.conf[index];
+        args.into_iter()
+            .enumerate()
+            .filter_map(|(index, arg)| conf.retains_arg(index).then_some(arg))
+            .collect()
+    }
+}
@@ -0,0 +1,126 @@
+//! Detection of integers coming from untrusted data, and of the checks bounding them, shared by
+//! the lints checking for such integers used as lengths, durations or enum discriminants.
+
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{
+    eq_expr_value, expr_or_init, fn_def_id, may_be_negative, path_to_local, path_to_local_id, peel_casts,
+};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+/// Where an integer found by [`untrusted_origin`] comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UntrustedOrigin {
+    /// Decoded from raw bytes with `from_le_bytes`, `from_be_bytes` or `from_ne_bytes`.
+    Decoded,
+    /// Cast from a signed integer that may be negative.
+    SignedCast,
+}
+
+/// If the integer `value` is, possibly through casts and local bindings, decoded from raw bytes
+/// or cast from a signed integer that may be negative, returns the expression where this
+/// happens.
+pub fn untrusted_origin<'a>(cx: &LateContext<'_>, mut value: &'a Expr<'a>) -> Option<(&'a Expr<'a>, UntrustedOrigin)> {
+    loop {
+        if let ExprKind::Cast(inner, _) = value.kind
+            && may_be_negative(cx, inner)
+        {
+            return Some((value, UntrustedOrigin::SignedCast));
+        }
+        let next = expr_or_init(cx, peel_casts(value));
+        if next.hir_id == value.hir_id {
+            break;
+        }
+        value = next;
+    }
+    if let ExprKind::Call(..) = value.kind
+        && let Some(def_id) = fn_def_id(cx, value)
+        && matches!(
+            cx.tcx.item_name(def_id).as_str(),
+            "from_le_bytes" | "from_be_bytes" | "from_ne_bytes"
+        )
+        && cx.typeck_results().expr_ty(value).is_integral()
+    {
+        Some((value, UntrustedOrigin::Decoded))
+    } else {
+        None
+    }
+}
+
+/// Checks whether one of the locals used in `value` is compared with something, or passed to
+/// `get`/`get_mut`, somewhere in `body` before `before`.
+///
+/// If `base` is given, only comparisons with an expression using `base.len()`, and calls to
+/// `base.get(..)`/`base.get_mut(..)`, count.
+pub fn is_checked_before<'tcx>(
+    cx: &LateContext<'tcx>,
+    body: &'tcx Expr<'tcx>,
+    value: &Expr<'_>,
+    before: &Expr<'_>,
+    base: Option<&Expr<'_>>,
+) -> bool {
+    let mut locals: Vec<HirId> = Vec::new();
+    for_each_expr(value, |e| {
+        if let Some(local) = path_to_local(e) {
+            locals.push(local);
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    if locals.is_empty() {
+        return false;
+    }
+    let uses_local = |e: &Expr<'_>| {
+        let e = expr_or_init(cx, e);
+        locals.iter().any(|&local| {
+            for_each_expr(e, |e| {
+                if path_to_local_id(e, local) {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            })
+            .is_some()
+        })
+    };
+    let uses_len_of_base = |e: &Expr<'_>| {
+        let Some(base) = base else {
+            return true;
+        };
+        for_each_expr(expr_or_init(cx, e), |e| {
+            if let ExprKind::MethodCall(method, receiver, [], _) = e.kind
+                && method.ident.name == sym::len
+                && eq_expr_value(cx, receiver, base)
+            {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_some()
+    };
+    for_each_expr(body, |e| {
+        if e.span.lo() >= before.span.lo() {
+            return ControlFlow::Continue(());
+        }
+        let is_check = match e.kind {
+            ExprKind::Binary(op, lhs, rhs) => {
+                op.node.is_comparison()
+                    && ((uses_local(lhs) && uses_len_of_base(rhs)) || (uses_local(rhs) && uses_len_of_base(lhs)))
+            },
+            ExprKind::MethodCall(method, receiver, [arg], _) => {
+                matches!(method.ident.as_str(), "get" | "get_mut")
+                    && uses_local(arg)
+                    && base.map_or(true, |base| eq_expr_value(cx, receiver, base))
+            },
+            _ => false,
+        };
+        if is_check {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
@@ -0,0 +1,92 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::visitors::find_all_ret_expressions;
+use clippy_utils::{expr_or_init, fn_def_id, match_def_path, paths, peel_casts};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, Expr, ExprKind, FnDecl};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::TypeVisitableExt;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+use rustc_target::spec::abi::Abi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `extern` functions returning a pointer obtained from `Box::into_raw` when the
+    /// boxed type is zero-sized, or is generic and may be zero-sized.
+    ///
+    /// ### Why is this bad?
+    /// A `Box` of a zero-sized type doesn't allocate. `Box::into_raw` returns a dangling,
+    /// non-null pointer that doesn't point to any memory, which C code expecting a real
+    /// allocation may read from, write to or pass to `free`.
+    ///
+    /// ### Known problems
+    /// A generic function is linted even if it is only ever called with types that aren't
+    /// zero-sized.
+    ///
+    /// ### Example
+    /// ```rust
+    /// extern "C" fn into_handle<T>(value: T) -> *mut std::ffi::c_void {
+    ///     Box::into_raw(Box::new(value)).cast()
+    /// }
+    /// ```
+    #[clippy::version = "1.74.0"]
+    pub ZST_BOX_PTR_ACROSS_FFI,
+    restriction,
+    "returning a pointer to a possibly zero-sized `Box` allocation from an `extern` function"
+}
+declare_lint_pass!(ZstBoxPtrAcrossFfi => [ZST_BOX_PTR_ACROSS_FFI]);
+
+impl<'tcx> LateLintPass<'tcx> for ZstBoxPtrAcrossFfi {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        _: LocalDefId,
+    ) {
+        if span.from_expansion() || kind.header().map_or(true, |header| header.abi == Abi::Rust) {
+            return;
+        }
+        find_all_ret_expressions(cx, body.value, |ret| {
+            check_ret(cx, ret);
+            true
+        });
+    }
+}
+
+fn check_ret<'tcx>(cx: &LateContext<'tcx>, ret: &'tcx Expr<'tcx>) {
+    let mut expr = peel_casts(ret);
+    while let ExprKind::MethodCall(method, receiver, [], _) = expr.kind
+        && matches!(method.ident.as_str(), "cast" | "cast_mut" | "cast_const")
+    {
+        expr = peel_casts(receiver);
+    }
+    let call = expr_or_init(cx, expr);
+    if let ExprKind::Call(_, [boxed]) = call.kind
+        && fn_def_id(cx, call).is_some_and(|def_id| match_def_path(cx, def_id, &paths::BOX_INTO_RAW))
+        && let boxed_ty = cx.typeck_results().expr_ty(boxed)
+        && boxed_ty.is_box()
+    {
+        let inner_ty = boxed_ty.boxed_ty();
+        let msg = if inner_ty.has_non_region_param() {
+            format!("returning a pointer to a `Box` allocation of `{inner_ty}`, which may be zero-sized")
+        } else if cx.layout_of(inner_ty).is_ok_and(|layout| layout.is_zst()) {
+            format!("returning a pointer to a `Box` allocation of the zero-sized type `{inner_ty}`")
+        } else {
+            return;
+        };
+        span_lint_and_help(
+            cx,
+            ZST_BOX_PTR_ACROSS_FFI,
+            ret.span,
+            &msg,
+            None,
+            "zero-sized allocations don't point to any memory; wrap the value in a type that isn't zero-sized, \
+            or allocate with the C allocator",
+        );
+    }
+}
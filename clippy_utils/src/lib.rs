@@ -111,9 +111,10 @@ use rustc_span::source_map::SourceMap;
 use rustc_span::symbol::{kw, Ident, Symbol};
 use rustc_span::{sym, Span};
 use rustc_target::abi::Integer;
+use rustc_target::spec::abi::Abi;
 use visitors::Visitable;
 
-use crate::consts::{constant, miri_to_const, Constant};
+use crate::consts::{constant, constant_full_int, miri_to_const, Constant, FullInt};
 use crate::higher::Range;
 use crate::ty::{
     adt_and_variant_of_res, can_partially_move_ty, expr_sig, is_copy, is_recursively_primitive_type,
@@ -2194,6 +2195,171 @@ pub fn fn_def_id(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<DefId> {
     }
 }
 
+/// Checks if `expr` is a call to a foreign function, i.e. one declared in an `extern` block.
+pub fn is_foreign_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    matches!(expr.kind, ExprKind::Call(..)) && fn_def_id(cx, expr).is_some_and(|id| cx.tcx.is_foreign_item(id))
+}
+
+/// Checks if `abi` is one of the Rust ABIs, as opposed to a foreign one like `extern "C"`.
+pub fn is_rust_abi(abi: Abi) -> bool {
+    matches!(abi, Abi::Rust | Abi::RustCall | Abi::RustIntrinsic | Abi::PlatformIntrinsic)
+}
+
+/// Checks if `expr` is a call to `ptr::null` or `ptr::null_mut`, or to a local `const fn` whose
+/// body is only such a call.
+pub fn is_null_path(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    if let ExprKind::Call(pathexp, []) = expr.kind
+        && let Some(id) = path_def_id(cx, pathexp)
+    {
+        is_null_fn(cx, id) || is_null_const_fn_wrapper(cx, id)
+    } else {
+        false
+    }
+}
+
+fn is_null_fn(cx: &LateContext<'_>, id: DefId) -> bool {
+    matches!(cx.tcx.get_diagnostic_name(id), Some(sym::ptr_null | sym::ptr_null_mut))
+}
+
+/// Checks if `id` is a local `const fn` whose body is only a call to `ptr::null` or
+/// `ptr::null_mut`. Wrappers of wrappers aren't followed, which keeps recursive functions from
+/// looping.
+fn is_null_const_fn_wrapper(cx: &LateContext<'_>, id: DefId) -> bool {
+    if let Some(local_id) = id.as_local()
+        && cx.tcx.is_const_fn(id)
+        && let Some(body_id) = cx.tcx.hir().maybe_body_owned_by(local_id)
+        && let ExprKind::Call(pathexp, []) = peel_blocks(cx.tcx.hir().body(body_id).value).kind
+        && let Some(callee) = path_def_id(cx, pathexp)
+    {
+        is_null_fn(cx, callee)
+    } else {
+        false
+    }
+}
+
+/// Checks if `expr` checks the local pointer `ptr_id` for null, with `is_null`, by comparing it
+/// to null, or by wrapping it in `NonNull::new`.
+pub fn is_null_check(cx: &LateContext<'_>, expr: &Expr<'_>, ptr_id: HirId) -> bool {
+    match expr.kind {
+        ExprKind::MethodCall(method, receiver, [], _) => {
+            method.ident.name.as_str() == "is_null" && path_to_local_id(receiver, ptr_id)
+        },
+        ExprKind::Binary(op, l, r) if matches!(op.node, hir::BinOpKind::Eq | hir::BinOpKind::Ne) => {
+            (path_to_local_id(l, ptr_id) && is_null_path(cx, r)) || (path_to_local_id(r, ptr_id) && is_null_path(cx, l))
+        },
+        ExprKind::Call(func, [arg]) => {
+            path_to_local_id(arg, ptr_id)
+                && path_def_id(cx, func).is_some_and(|id| match_def_path(cx, id, &paths::PTR_NON_NULL_NEW))
+        },
+        _ => false,
+    }
+}
+
+/// If `expr` takes the address of a local variable (or a field or array element of one), or
+/// points into a local array through `as_ptr`/`as_mut_ptr`, returns the `HirId` of the local.
+/// Looks through pointer casts.
+pub fn stack_local_address(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<HirId> {
+    let mut place = match peel_ptr_casts(expr).kind {
+        ExprKind::AddrOf(_, _, place) => place,
+        ExprKind::MethodCall(method, receiver, [], _)
+            if matches!(method.ident.as_str(), "as_ptr" | "as_mut_ptr")
+                && matches!(cx.typeck_results().expr_ty(receiver).kind(), rustc_ty::Array(..)) =>
+        {
+            receiver
+        },
+        _ => return None,
+    };
+    loop {
+        match place.kind {
+            // Projections through a reference, a raw pointer or a `Box` leave the stack frame.
+            ExprKind::Field(base, _) => {
+                let base_ty = cx.typeck_results().expr_ty(base);
+                if base_ty.is_any_ptr() || base_ty.is_box() {
+                    return None;
+                }
+                place = base;
+            },
+            ExprKind::Index(base, _, _) if matches!(cx.typeck_results().expr_ty(base).kind(), rustc_ty::Array(..)) => {
+                place = base;
+            },
+            _ => return path_to_local(place),
+        }
+    }
+}
+
+/// Returns the container the pointer `expr` was taken out of with `as_ptr` or `as_mut_ptr`,
+/// looking through casts, offsets and local bindings.
+pub fn as_ptr_source<'a>(cx: &LateContext<'_>, expr: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+    let mut expr = expr;
+    loop {
+        expr = peel_casts(expr_or_init(cx, peel_casts(expr)));
+        match expr.kind {
+            ExprKind::MethodCall(method, receiver, [], _) if method.ident.as_str() == "cast" => expr = receiver,
+            ExprKind::MethodCall(method, receiver, [_], _)
+                if matches!(
+                    method.ident.as_str(),
+                    "add" | "offset" | "sub" | "wrapping_add" | "wrapping_offset" | "wrapping_sub"
+                ) =>
+            {
+                expr = receiver;
+            },
+            ExprKind::MethodCall(method, receiver, [], _)
+                if matches!(method.ident.as_str(), "as_ptr" | "as_mut_ptr") =>
+            {
+                return Some(receiver);
+            },
+            _ => return None,
+        }
+    }
+}
+
+/// Returns the expressions of the statements following the `i`th one in `block`, i.e. the
+/// initializers of `let` statements and the expressions of expression statements.
+pub fn following_exprs<'tcx>(block: &Block<'tcx>, i: usize) -> impl Iterator<Item = &'tcx Expr<'tcx>> + '_ {
+    block.stmts[i + 1..].iter().filter_map(|stmt| match stmt.kind {
+        StmtKind::Local(local) => local.init,
+        StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+        StmtKind::Item(_) => None,
+    })
+}
+
+/// Checks if `value` is a signed integer that isn't known to be non-negative.
+pub fn may_be_negative(cx: &LateContext<'_>, value: &Expr<'_>) -> bool {
+    let typeck = cx.typeck_results();
+    typeck.expr_ty(value).is_signed()
+        && !matches!(constant_full_int(cx, typeck, value), Some(FullInt::S(0..)))
+        // e.g. `vec.len() as i32`
+        && !matches!(typeck.expr_ty(peel_casts(value)).kind(), rustc_ty::Uint(_))
+}
+
+/// Returns the type of a `size_of` or `size_of_val` call multiplying `expr`, or dividing it if
+/// `inverted` is set.
+pub fn get_size_of_ty<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, inverted: bool) -> Option<Ty<'tcx>> {
+    match expr.kind {
+        ExprKind::Call(count_func, _func_args) => {
+            if_chain! {
+                if !inverted;
+                if let ExprKind::Path(ref count_func_qpath) = count_func.kind;
+                if let Some(def_id) = cx.qpath_res(count_func_qpath, count_func.hir_id).opt_def_id();
+                if matches!(cx.tcx.get_diagnostic_name(def_id), Some(sym::mem_size_of | sym::mem_size_of_val));
+                then {
+                    cx.typeck_results().node_args(count_func.hir_id).types().next()
+                } else {
+                    None
+                }
+            }
+        },
+        ExprKind::Binary(op, left, right) if hir::BinOpKind::Mul == op.node => {
+            get_size_of_ty(cx, left, inverted).or_else(|| get_size_of_ty(cx, right, inverted))
+        },
+        ExprKind::Binary(op, left, right) if hir::BinOpKind::Div == op.node => {
+            get_size_of_ty(cx, left, inverted).or_else(|| get_size_of_ty(cx, right, !inverted))
+        },
+        ExprKind::Cast(expr, _) => get_size_of_ty(cx, expr, inverted),
+        _ => None,
+    }
+}
+
 /// Returns `Option<String>` where String is a textual representation of the type encapsulated in
 /// the slice iff the given expression is a slice of primitives (as defined in the
 /// `is_recursively_primitive_type` function) and `None` otherwise.
@@ -2335,6 +2501,42 @@ pub fn peel_hir_expr_refs<'a>(expr: &'a Expr<'a>) -> (&'a Expr<'a>, usize) {
     (e, count)
 }
 
+/// Peels off all `as` casts on the expression, e.g. `&x as *const u8 as *const u32` becomes `&x`.
+pub fn peel_casts<'a>(expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    peel_hir_expr_while(expr, |e| match e.kind {
+        ExprKind::Cast(e, _) => Some(e),
+        _ => None,
+    })
+}
+
+/// Peels off all pointer casts on the expression, i.e. `as` casts as well as calls to `cast`,
+/// `cast_mut` and `cast_const`.
+pub fn peel_ptr_casts<'a>(mut expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    while let ExprKind::MethodCall(method, receiver, [], _) = peel_casts(expr).kind
+        && matches!(method.ident.as_str(), "cast" | "cast_mut" | "cast_const")
+    {
+        expr = receiver;
+    }
+    peel_casts(expr)
+}
+
+/// Peels off all `as` casts and the blocks wrapping the expression without any statements,
+/// including `unsafe` blocks, e.g. `unsafe { f() as i64 }` becomes `f()`.
+pub fn peel_blocks_and_casts<'a>(expr: &'a Expr<'a>) -> &'a Expr<'a> {
+    let mut expr = peel_casts(expr);
+    while let ExprKind::Block(block, _) = expr.kind
+        && block.stmts.is_empty()
+        && let Some(inner) = block.expr
+        && matches!(
+            block.rules,
+            BlockCheckMode::DefaultBlock | BlockCheckMode::UnsafeBlock(hir::UnsafeSource::UserProvided)
+        )
+    {
+        expr = peel_casts(inner);
+    }
+    expr
+}
+
 /// Peels off all references on the type. Returns the underlying type and the number of references
 /// removed.
 pub fn peel_hir_ty_refs<'a>(mut ty: &'a hir::Ty<'a>) -> (&'a hir::Ty<'a>, usize) {
@@ -111,6 +111,22 @@ pub fn for_each_expr<'tcx, B, C: Continue>(
     v.res
 }
 
+/// Returns the span of the first expression in `block` that starts after `after` and matches
+/// `pred`.
+pub fn first_after<'tcx>(
+    block: &'tcx Block<'tcx>,
+    after: Span,
+    mut pred: impl FnMut(&'tcx Expr<'tcx>) -> bool,
+) -> Option<Span> {
+    for_each_expr(block, |e| {
+        if e.span.lo() > after.hi() && pred(e) {
+            ControlFlow::Break(e.span)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
 /// Calls the given function once for each expression contained. This will enter bodies, but not
 /// nested items.
 pub fn for_each_expr_with_closures<'tcx, B, C: Continue>(
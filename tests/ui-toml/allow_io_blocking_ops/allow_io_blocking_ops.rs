@@ -0,0 +1,13 @@
+#![warn(clippy::blocking_op_in_async)]
+
+use std::io::{self, Write};
+
+async fn prompt() -> String {
+    print!("> ");
+    io::stdout().flush().unwrap();
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).unwrap();
+    line
+}
+
+fn main() {}
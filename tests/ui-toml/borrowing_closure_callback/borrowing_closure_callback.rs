@@ -0,0 +1,31 @@
+#![warn(clippy::borrowing_closure_callback)]
+
+use std::ffi::c_void;
+
+fn register_callback(_data: *mut c_void) {}
+fn inspect(_data: *mut c_void) {}
+
+fn main() {
+    let mut count = 0;
+    let callback = Box::new(|| count += 1);
+    register_callback(Box::into_raw(callback).cast());
+    //~^ ERROR: passing a closure that borrows `count` to a function that retains it
+
+    let name = String::from("foo");
+    let describe = || name.len();
+    let boxed: Box<Box<dyn Fn() -> usize>> = Box::new(Box::new(describe));
+    register_callback(Box::into_raw(boxed) as *mut c_void);
+    //~^ ERROR: passing a closure that borrows `name` to a function that retains it
+
+    // ok, the captured value is moved into the closure
+    let owned = String::from("bar");
+    let callback = Box::new(move || owned.len());
+    register_callback(Box::into_raw(callback).cast());
+
+    // ok, nothing is captured
+    register_callback(Box::leak(Box::new(|| 42)) as *mut _ as *mut c_void);
+
+    // ok, not a retaining function
+    let mut total = 0;
+    inspect(Box::into_raw(Box::new(|| total += 1)).cast());
+}
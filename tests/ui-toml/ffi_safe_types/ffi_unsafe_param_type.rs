@@ -0,0 +1,12 @@
+#![warn(clippy::ffi_unsafe_param_type)]
+#![allow(improper_ctypes, improper_ctypes_definitions)]
+
+extern "C" {
+    // ok, `String` is configured as FFI-safe
+    fn make_name(id: u32) -> String;
+    fn free_name(name: String);
+
+    fn take_bytes(bytes: Vec<u8>);
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![warn(clippy::fs_toctou)]
+
+use std::fs::File;
+use std::path::Path;
+
+mod vfs {
+    pub fn exists(_path: &str) -> bool {
+        true
+    }
+
+    pub fn open(_path: &str) -> Option<u32> {
+        Some(4)
+    }
+}
+
+fn main() {
+    let name = "config.toml";
+    if vfs::exists(name) {
+        let _ = vfs::open(name);
+        //~^ ERROR: `name` is used after being checked, but the filesystem may have changed in between
+    }
+    if Path::new(name).exists() {
+        let _ = vfs::open(name);
+        //~^ ERROR: `name` is used after being checked, but the filesystem may have changed in between
+    }
+
+    // ok, not configured as a use
+    if Path::new(name).exists() {
+        let _ = File::open(name);
+    }
+}
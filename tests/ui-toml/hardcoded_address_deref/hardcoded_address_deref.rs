@@ -0,0 +1,15 @@
+#![warn(clippy::hardcoded_address_deref)]
+
+const GPIO_BASE: usize = 0x4002_0000;
+
+fn main() {
+    unsafe {
+        // ok, inside the configured range
+        let gpio = GPIO_BASE as *mut u32;
+        *gpio = 1;
+        let _status = *((GPIO_BASE + 0x10) as *const u32);
+
+        let _past_end = *(0x5000_0000 as *const u32);
+        //~^ ERROR: dereferencing a pointer to the hardcoded address `0x50000000`
+    }
+}
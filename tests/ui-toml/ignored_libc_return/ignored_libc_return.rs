@@ -0,0 +1,20 @@
+#![warn(clippy::ignored_libc_return)]
+
+mod sys {
+    pub fn release(_handle: u32) -> i32 {
+        0
+    }
+
+    extern "C" {
+        pub fn close(fd: i32) -> i32;
+    }
+}
+
+fn main() {
+    sys::release(1);
+    //~^ ERROR: the return value of this call is ignored
+    // the default functions are still checked
+    unsafe { sys::close(2) };
+    //~^ ERROR: the return value of this call is ignored
+    if sys::release(3) != 0 {}
+}
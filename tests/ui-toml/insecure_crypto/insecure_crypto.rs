@@ -0,0 +1,80 @@
+#![warn(clippy::insecure_crypto)]
+
+mod md5 {
+    pub fn compute(_data: &[u8]) -> [u8; 16] {
+        [0; 16]
+    }
+}
+
+// Mirrors the RustCrypto crates, where the hashes are type aliases of a shared wrapper
+mod digest {
+    pub trait Digest {
+        fn new() -> Self;
+        fn digest(data: &[u8]) -> Vec<u8>;
+    }
+
+    pub struct CoreWrapper<T>(T);
+
+    impl<T: Default> Digest for CoreWrapper<T> {
+        fn new() -> Self {
+            CoreWrapper(T::default())
+        }
+        fn digest(data: &[u8]) -> Vec<u8> {
+            data.to_vec()
+        }
+    }
+}
+
+mod sha1 {
+    #[derive(Default)]
+    pub struct Sha1Core;
+
+    pub type Sha1 = super::digest::CoreWrapper<Sha1Core>;
+}
+
+mod sha2 {
+    #[derive(Default)]
+    pub struct Sha256Core;
+
+    pub type Sha256 = super::digest::CoreWrapper<Sha256Core>;
+}
+
+mod rc4 {
+    pub struct Rc4 {
+        pub key: Vec<u8>,
+    }
+
+    impl Rc4 {
+        pub fn new(key: &[u8]) -> Self {
+            Self { key: key.to_vec() }
+            //~^ ERROR: use of a broken cryptographic primitive
+        }
+    }
+}
+
+mod ecb {
+    pub struct Ecb(pub u8);
+}
+
+use digest::Digest;
+
+fn main() {
+    let _ = md5::compute(b"data");
+    //~^ ERROR: use of a broken cryptographic primitive
+    let _ = sha1::Sha1::digest(b"data");
+    //~^ ERROR: use of a broken cryptographic primitive
+    let _ = sha1::Sha1::new();
+    //~^ ERROR: use of a broken cryptographic primitive
+    let _: sha1::Sha1 = Digest::new();
+    //~^ ERROR: use of a broken cryptographic primitive
+    let _ = rc4::Rc4::new(b"key");
+    //~^ ERROR: use of a broken cryptographic primitive
+    let _ = rc4::Rc4 { key: vec![] };
+    //~^ ERROR: use of a broken cryptographic primitive
+    let _ = ecb::Ecb(0);
+    //~^ ERROR: use of a broken cryptographic primitive
+
+    // ok, the same wrapper around a secure hash
+    let _ = sha2::Sha256::digest(b"data");
+    let _ = sha2::Sha256::new();
+}
@@ -0,0 +1,62 @@
+#![warn(clippy::mismatched_resource_release)]
+
+mod ffi {
+    pub struct Lock(pub u32);
+    pub struct Pool(pub u32);
+
+    pub unsafe fn lock_init(lock: *mut Lock, flags: u32) -> i32 {
+        0
+    }
+
+    pub unsafe fn lock_destroy(lock: *mut Lock) -> i32 {
+        0
+    }
+
+    pub unsafe fn pool_create(pool: *mut Pool) -> i32 {
+        0
+    }
+
+    pub unsafe fn pool_free(flags: u32, pool: *mut Pool) {}
+}
+
+use ffi::{Lock, Pool};
+use std::ptr;
+
+fn mismatched() {
+    let mut first = Lock(0);
+    let mut second = Lock(0);
+    unsafe {
+        ffi::lock_init(&mut first, 0);
+        ffi::lock_destroy(&mut second);
+        //~^ ERROR: releasing `&mut second` with `lock_destroy`, but it isn't the handle that wa
+    }
+
+    let mut pool = Pool(0);
+    let mut other = Pool(0);
+    unsafe {
+        ffi::pool_create(&mut pool);
+        ffi::pool_free(0, &mut other);
+        //~^ ERROR: releasing `&mut other` with `pool_free`, but it isn't the handle that was
+    }
+}
+
+fn matched() {
+    let mut lock = Lock(0);
+    let handle = &mut lock as *mut Lock;
+    unsafe {
+        ffi::lock_init(handle, 0);
+        ffi::lock_destroy(&mut lock);
+    }
+
+    let mut pool = Pool(0);
+    unsafe {
+        ffi::pool_create(&mut pool);
+        ffi::pool_free(0, ptr::addr_of_mut!(pool));
+    }
+}
+
+fn released_elsewhere(lock: &mut Lock) {
+    unsafe { ffi::lock_destroy(lock) };
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#![warn(clippy::missing_errno_check)]
+
+mod sys {
+    pub fn acquire() -> u32 {
+        1
+    }
+}
+
+fn main() {
+    let first = sys::acquire();
+    //~^ ERROR: the value returned by `acquire` isn't compared to `0` before `errno` may change
+    if first == u32::MAX {
+        return;
+    }
+    let second = sys::acquire();
+    if second != 0 {
+        println!("{first} {second}");
+    }
+}
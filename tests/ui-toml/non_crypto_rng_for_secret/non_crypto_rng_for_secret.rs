@@ -0,0 +1,82 @@
+#![warn(clippy::non_crypto_rng_for_secret)]
+
+mod rand {
+    pub struct ThreadRng;
+    pub struct SmallRng;
+    pub struct OsRng;
+
+    pub trait Rng {
+        fn next_u64(&mut self) -> u64 {
+            4
+        }
+        fn fill(&mut self, dest: &mut [u8]) {
+            dest.fill(4);
+        }
+    }
+
+    impl Rng for ThreadRng {}
+    impl Rng for SmallRng {}
+    impl Rng for OsRng {}
+
+    pub fn thread_rng() -> ThreadRng {
+        ThreadRng
+    }
+
+    pub fn random() -> u64 {
+        4
+    }
+}
+
+use rand::Rng;
+
+struct Session {
+    token: u64,
+    user: u64,
+}
+
+struct Cipher {
+    key: [u8; 32],
+}
+
+fn main() {
+    let mut key = [0u8; 32];
+    rand::thread_rng().fill(&mut key);
+    //~^ ERROR: `key` is generated with a random number generator that isn't cryptographically secure
+
+    let mut rng = rand::SmallRng;
+    let mut nonce = [0u8; 12];
+    rng.fill(&mut nonce[..8]);
+    //~^ ERROR: `nonce` is generated with a random number generator that isn't cryptographically secure
+    let salt = rng.next_u64();
+    //~^ ERROR: `salt` is generated with a random number generator that isn't cryptographically secure
+    let api_token = rand::random();
+    //~^ ERROR: `api_token` is generated with a random number generator that isn't cryptographically secure
+    let seed = rand::random();
+    //~^ ERROR: `seed` is generated with a random number generator that isn't cryptographically secure
+
+    let mut session = Session {
+        token: rng.next_u64(),
+        //~^ ERROR: `token` is generated with a random number generator that isn't cryptographically secure
+        user: rng.next_u64(),
+    };
+
+    let mut cipher = Cipher { key: [0; 32] };
+    rng.fill(&mut cipher.key);
+    //~^ ERROR: `key` is generated with a random number generator that isn't cryptographically secure
+
+    session.token = rand::random();
+    //~^ ERROR: `token` is generated with a random number generator that isn't cryptographically secure
+
+    // ok, the OS generator is cryptographically secure
+    let mut iv = [0u8; 16];
+    rand::OsRng.fill(&mut iv);
+
+    // ok, not secrets
+    let mut buf = [0u8; 32];
+    rng.fill(&mut buf);
+    let monkey = rng.next_u64();
+    let ivory = rand::random();
+    let key_rng = rand::thread_rng();
+
+    let _ = (salt, api_token, seed, session.token, session.user, monkey, ivory, key_rng);
+}
@@ -0,0 +1,67 @@
+#![warn(clippy::stack_ptr_escapes_via_call)]
+#![allow(clippy::borrow_as_ptr)]
+
+use std::ptr::addr_of;
+
+struct Config {
+    level: u32,
+    buf: [u8; 4],
+}
+
+static GLOBAL: Config = Config { level: 0, buf: [0; 4] };
+
+fn register(_config: *const Config) {}
+fn register_level(_level: *const u32) {}
+fn inspect(_config: *const Config) {}
+fn set_buffer(_scratch: *mut u8, _buffer: *mut u8) {}
+
+static mut STATIC_BUF: [u8; 16] = [0; 16];
+
+struct Registry;
+
+impl Registry {
+    fn add(&mut self, _config: *const Config) {}
+}
+
+fn main() {
+    let config = Config { level: 1, buf: [0; 4] };
+    register(&config as *const Config);
+    //~^ ERROR: passing the address of the stack local `config` to a function that retains it
+    register(addr_of!(config));
+    //~^ ERROR: passing the address of the stack local `config` to a function that retains it
+
+    let mut registry = Registry;
+    registry.add(&config);
+    //~^ ERROR: passing the address of the stack local `config` to a function that retains it
+
+    // ok, `static`
+    register(&GLOBAL as *const Config);
+
+    // ok, heap allocated
+    let boxed = Box::new(Config { level: 2, buf: [0; 4] });
+    register(&*boxed as *const Config);
+    let level = &boxed.level;
+    register_level(level);
+
+    let mut buf = [0u8; 16];
+    let mut scratch = [0u8; 16];
+    set_buffer(scratch.as_mut_ptr(), buf.as_mut_ptr());
+    //~^ ERROR: passing the address of the stack local `buf` to a function that retains it
+    set_buffer(scratch.as_mut_ptr(), &mut buf as *mut [u8; 16] as *mut u8);
+    //~^ ERROR: passing the address of the stack local `buf` to a function that retains it
+    register_level(&config.level);
+    //~^ ERROR: passing the address of the stack local `config` to a function that retains it
+    register_level(config.buf.as_ptr().cast());
+    //~^ ERROR: passing the address of the stack local `config` to a function that retains it
+
+    // ok, `static` and heap allocated buffers
+    let mut heap_buf = Box::new([0u8; 16]);
+    unsafe { set_buffer(scratch.as_mut_ptr(), STATIC_BUF.as_mut_ptr()) };
+    set_buffer(scratch.as_mut_ptr(), heap_buf.as_mut_ptr());
+    let mut vec_buf = vec![0u8; 16];
+    set_buffer(scratch.as_mut_ptr(), vec_buf.as_mut_ptr());
+
+    // ok, not a retaining function
+    inspect(&config as *const Config);
+    let _ = (config.level, config.buf, boxed.buf);
+}
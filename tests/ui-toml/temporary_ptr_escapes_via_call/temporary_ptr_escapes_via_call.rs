@@ -0,0 +1,38 @@
+#![warn(clippy::temporary_ptr_escapes_via_call)]
+#![allow(clippy::borrow_as_ptr)]
+
+struct Config {
+    level: u32,
+}
+
+const DEFAULT: Config = Config { level: 0 };
+
+fn set_config(_config: *const Config) {}
+fn set_buffer(_buf: *const u8) {}
+fn print_config(_config: *const Config) {}
+
+fn make_config() -> Config {
+    Config { level: 1 }
+}
+
+fn make_buffer() -> Vec<u8> {
+    vec![0; 16]
+}
+
+fn main() {
+    set_config(&make_config() as *const _);
+    //~^ ERROR: passing the address of a temporary to a function that retains it
+    set_buffer(make_buffer().as_ptr());
+    //~^ ERROR: passing the address of a temporary to a function that retains it
+
+    // ok, promoted to `'static`
+    set_config(&DEFAULT as *const _);
+
+    // ok, heap allocated
+    let config = Box::new(make_config());
+    set_config(&*config as *const _);
+
+    // ok, not a retaining function
+    print_config(&make_config() as *const _);
+    let _ = config.level;
+}
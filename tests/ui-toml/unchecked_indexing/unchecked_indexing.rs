@@ -0,0 +1,12 @@
+#![warn(clippy::unchecked_indexing)]
+
+fn safe(values: &[u32], n: usize) -> u32 {
+    values[n]
+    //~^ ERROR: indexing with an index that isn't bounds-checked
+}
+
+fn checked(values: &[u32], n: usize) -> u32 {
+    if n < values.len() { values[n] } else { 0 }
+}
+
+fn main() {}
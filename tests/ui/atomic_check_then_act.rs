@@ -0,0 +1,52 @@
+#![warn(clippy::atomic_check_then_act)]
+#![allow(clippy::collapsible_if)]
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+const MAX: usize = 10;
+
+fn increment(counter: &AtomicUsize) {
+    if counter.load(Ordering::Relaxed) < MAX {
+        counter.store(counter.load(Ordering::Relaxed) + 1, Ordering::Relaxed);
+        //~^ ERROR: storing a value derived from a separate load of an atomic that was checked
+    }
+}
+
+fn toggle(flag: &AtomicBool) {
+    if flag.load(Ordering::Acquire) {
+        let value = !flag.load(Ordering::Acquire);
+        flag.store(value, Ordering::Release);
+        flag.store(!flag.load(Ordering::Acquire), Ordering::Release);
+        //~^ ERROR: storing a value derived from a separate load of an atomic that was checked
+    }
+}
+
+fn nested(counter: &AtomicUsize) {
+    if counter.load(Ordering::Relaxed) > 0 {
+        if counter.load(Ordering::Relaxed) < MAX {
+            counter.store(counter.load(Ordering::Relaxed) * 2, Ordering::Relaxed);
+            //~^ ERROR: storing a value derived from a separate load of an atomic that was checked
+        }
+    }
+}
+
+fn no_lint(counter: &AtomicUsize, other: &AtomicUsize) {
+    // the update itself is atomic
+    if counter.load(Ordering::Relaxed) < MAX {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+    // the stored value doesn't depend on the atomic
+    if counter.load(Ordering::Relaxed) < MAX {
+        counter.store(0, Ordering::Relaxed);
+    }
+    // a different atomic is updated
+    if counter.load(Ordering::Relaxed) < MAX {
+        other.store(other.load(Ordering::Relaxed) + 1, Ordering::Relaxed);
+    }
+    // the atomic isn't checked
+    if other.load(Ordering::Relaxed) < MAX {
+        counter.store(counter.load(Ordering::Relaxed) + 1, Ordering::Relaxed);
+    }
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#![warn(clippy::blocking_call_without_timeout)]
+
+use std::sync::mpsc::channel;
+use std::sync::{Mutex, RwLock};
+use std::time::Duration;
+
+fn main() {
+    let (tx, rx) = channel::<u32>();
+    tx.send(1).unwrap();
+    let _ = rx.recv();
+    //~^ ERROR: calling `recv` may block indefinitely
+
+    let mutex = Mutex::new(0);
+    let _ = mutex.lock();
+    //~^ ERROR: calling `lock` may block indefinitely
+
+    let rwlock = RwLock::new(0);
+    let _ = rwlock.read();
+    //~^ ERROR: calling `read` may block indefinitely
+    let _ = rwlock.write();
+    //~^ ERROR: calling `write` may block indefinitely
+
+    // ok, bounded
+    let _ = rx.recv_timeout(Duration::from_secs(1));
+    let _ = mutex.try_lock();
+    let _ = rwlock.try_read();
+    let _ = rwlock.try_write();
+}
@@ -0,0 +1,70 @@
+#![warn(clippy::blocking_op_in_async)]
+
+extern crate futures;
+
+use futures::executor::block_on;
+use std::future::Future;
+use std::io::{self, Write};
+use std::sync::{Barrier, Condvar, MutexGuard};
+
+async fn answer() -> u32 {
+    42
+}
+
+async fn nested() -> u32 {
+    block_on(answer())
+    //~^ ERROR: calling `block_on` inside an `async` body may deadlock the executor
+}
+
+fn in_block() -> impl Future<Output = u32> {
+    async { futures::executor::block_on(answer()) + 1 }
+    //~^ ERROR: calling `block_on` inside an `async` body may deadlock the executor
+}
+
+async fn awaited() -> u32 {
+    answer().await
+}
+
+async fn on_other_thread() -> u32 {
+    // ok, blocks another thread
+    std::thread::spawn(|| block_on(answer())).join().unwrap()
+}
+
+async fn read_input() -> String {
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).unwrap();
+    //~^ ERROR: calling `read_line` inside an `async` body blocks the executor
+    line
+}
+
+async fn flush_output() {
+    io::stdout().flush().unwrap();
+    //~^ ERROR: calling `flush` inside an `async` body blocks the executor
+    Write::flush(&mut io::stdout()).unwrap();
+    //~^ ERROR: calling `flush` inside an `async` body blocks the executor
+    // ok, not the standard output
+    Vec::<u8>::new().flush().unwrap();
+}
+
+async fn wait_ready(ready: MutexGuard<'_, bool>, cvar: &Condvar) -> bool {
+    *cvar.wait(ready).unwrap()
+    //~^ ERROR: calling `wait` inside an `async` body blocks the executor
+}
+
+async fn rendezvous(barrier: &Barrier) {
+    barrier.wait();
+    //~^ ERROR: calling `wait` inside an `async` body blocks the executor
+}
+
+async fn never_awaits(barrier: &Barrier) -> u32 {
+    barrier.wait();
+    //~^ ERROR: calling `wait` inside an `async` body blocks the executor
+    // the `.await` of an inner `async` block doesn't count
+    let inner = async { answer().await + 1 };
+    1
+}
+
+fn main() {
+    // ok, not inside an `async` body
+    let _ = block_on(nested());
+}
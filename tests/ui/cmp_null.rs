@@ -17,4 +17,20 @@ fn main() {
         //~^ ERROR: comparing with null is better expressed by the `.is_null()` method
         println!("This is surprising, too!");
     }
+    if p == my_null() {
+        //~^ ERROR: comparing with null is better expressed by the `.is_null()` method
+        println!("Null through a wrapper");
+    }
+    // ok, not always null
+    if p == maybe_null(false, p) {
+        println!("Not necessarily null");
+    }
+}
+
+const fn my_null<T>() -> *const T {
+    ptr::null()
+}
+
+const fn maybe_null<T>(null: bool, p: *const T) -> *const T {
+    if null { ptr::null() } else { p }
 }
@@ -0,0 +1,32 @@
+#![warn(clippy::cstr_from_unterminated_ptr)]
+
+use std::ffi::{c_char, CStr};
+
+extern "C" {
+    fn getenv(name: *const c_char) -> *const c_char;
+}
+
+fn main() {
+    let bytes = vec![b'h', b'i'];
+    let _ = unsafe { CStr::from_ptr(bytes.as_ptr().cast()) };
+    //~^ ERROR: creating a `CStr` from a pointer to a byte buffer that is not known to be NUL-terminated
+
+    let slice: &[u8] = &bytes;
+    let _ = unsafe { CStr::from_ptr(slice.as_ptr() as *const c_char) };
+    //~^ ERROR: creating a `CStr` from a pointer to a byte buffer that is not known to be NUL-terminated
+
+    let array = [1i8, 2, 3];
+    let ptr = array.as_ptr();
+    let _ = unsafe { CStr::from_ptr(ptr) };
+    //~^ ERROR: creating a `CStr` from a pointer to a byte buffer that is not known to be NUL-terminated
+
+    // ok, NUL-terminated literal
+    let _ = unsafe { CStr::from_ptr(b"hi\0".as_ptr().cast()) };
+
+    // ok, the pointer comes from a C function
+    let _ = unsafe { CStr::from_ptr(getenv(b"HOME\0".as_ptr().cast())) };
+
+    // ok, not a byte buffer
+    let words = [1u32, 2, 0];
+    let _ = unsafe { CStr::from_ptr(words.as_ptr().cast()) };
+}
@@ -0,0 +1,34 @@
+#![warn(clippy::cstr_used_after_buffer_mutation)]
+
+use std::ffi::CStr;
+
+fn cleared() {
+    let mut buf = vec![b'a', 0];
+    let name = unsafe { CStr::from_ptr(buf.as_ptr().cast()) };
+    buf.clear();
+    println!("{:?}", name);
+}
+
+fn pushed() {
+    let mut buf = vec![1u8, 2, 3];
+    let bytes = unsafe { std::slice::from_raw_parts(buf.as_ptr(), buf.len()) };
+    buf.push(4);
+    println!("{:?}", bytes);
+}
+
+fn assigned() {
+    let mut buf = [b'a', b'b', 0];
+    let name = unsafe { CStr::from_ptr(buf.as_ptr().cast()) };
+    buf[0] = b'c';
+    assert_eq!(name.to_bytes(), b"ab");
+}
+
+// ok, the buffer is modified after the last use
+fn used_before() {
+    let mut buf = vec![b'a', 0];
+    let name = unsafe { CStr::from_ptr(buf.as_ptr().cast()) };
+    println!("{:?}", name);
+    buf.clear();
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+#![warn(clippy::cstring_used_after_into_raw)]
+
+use std::ffi::{c_char, CString};
+
+extern "C" {
+    fn take_ownership(s: *mut c_char);
+    fn print(s: *const c_char);
+}
+
+fn main() {
+    let name = CString::new("foo").unwrap();
+    let ptr = name.as_ptr();
+    unsafe { take_ownership(name.into_raw()) };
+    unsafe { print(ptr) };
+    //~^ ERROR: using a pointer into a `CString` after its ownership was given up with `into_raw`
+
+    let name = CString::new("foo").unwrap();
+    let bytes = name.as_bytes().as_ptr();
+    let raw = name.into_raw();
+    let _ = unsafe { *bytes };
+    //~^ ERROR: using a pointer into a `CString` after its ownership was given up with `into_raw`
+    unsafe { take_ownership(raw) };
+
+    // the pointer is only used before `into_raw`
+    let name = CString::new("foo").unwrap();
+    let ptr = name.as_ptr();
+    unsafe { print(ptr) };
+    unsafe { take_ownership(name.into_raw()) };
+
+    // the pointer returned by `into_raw` is fine to use
+    let name = CString::new("foo").unwrap();
+    let raw = name.into_raw();
+    unsafe { print(raw) };
+    unsafe { take_ownership(raw) };
+}
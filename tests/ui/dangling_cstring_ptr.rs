@@ -0,0 +1,38 @@
+#![warn(clippy::dangling_cstring_ptr)]
+
+use std::ffi::{c_char, CString, NulError};
+
+extern "C" {
+    fn puts(s: *const c_char) -> i32;
+}
+
+struct Holder {
+    ptr: *const c_char,
+}
+
+fn make(s: &str) -> CString {
+    CString::new(s).unwrap()
+}
+
+fn bound(s: &str) -> Result<(), NulError> {
+    let p = CString::new(s)?.as_ptr();
+    unsafe { puts(p) };
+
+    let _bytes = make(s).as_ptr() as *const u8;
+
+    let mut holder = Holder { ptr: std::ptr::null() };
+    holder.ptr = make(s).as_ptr();
+    unsafe { puts(holder.ptr) };
+    Ok(())
+}
+
+fn not_dangling(s: &str) {
+    let owned = make(s);
+    let p = owned.as_ptr();
+    unsafe { puts(p) };
+
+    // the temporary lives until the end of the statement
+    unsafe { puts(make(s).as_ptr()) };
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+#![warn(clippy::ffi_integer_truncation)]
+
+extern "C" {
+    fn send(len: u32) -> i64;
+    fn set_mode(mode: u8);
+}
+
+fn rust_fn(len: u32) {}
+
+const MODE: u32 = 3;
+
+fn main() {
+    let n: usize = 1024;
+    let big: u64 = 4096;
+    let small: u16 = 7;
+    unsafe {
+        send(n as u32);
+        set_mode(big as u8);
+        let sent = send(16) as i32;
+
+        // Constants that fit
+        set_mode(MODE as u8);
+
+        // Range checked
+        if n <= u32::MAX as usize {
+            send(n as u32);
+        }
+        set_mode((big & 0xff) as u8);
+        set_mode(big.min(255) as u8);
+
+        // Widening
+        send(small as u32);
+    }
+    // Not a foreign function
+    rust_fn(big as u32);
+}
+
+mod lengths {
+    use std::ffi::c_int;
+
+    extern "C" {
+        fn write_buf(buf: *const u8, len: c_int) -> c_int;
+        fn seek(offset: isize);
+        fn read_buf(buf: *mut u8, len: usize) -> usize;
+    }
+
+    fn lengths(buf: &mut [u8]) {
+        unsafe {
+            write_buf(buf.as_ptr(), buf.len() as c_int);
+            seek(buf.len() as isize);
+            let read = read_buf(buf.as_mut_ptr(), buf.len()) as isize;
+        }
+    }
+}
@@ -0,0 +1,37 @@
+#![warn(clippy::ffi_output_niche_field)]
+
+#[repr(C)]
+enum Kind {
+    File,
+    Directory,
+}
+
+#[repr(C)]
+struct Status {
+    code: i32,
+    done: bool,
+    //~^ ERROR: the field `done` of type `bool` is filled by a foreign function, which may write an invalid
+    kind: Kind,
+    //~^ ERROR: the field `kind` of type `Kind` is filled by a foreign function, which may write an invalid
+}
+
+#[repr(C)]
+struct Options {
+    verbose: bool,
+}
+
+#[repr(C)]
+struct Validated {
+    done: u8,
+}
+
+extern "C" {
+    fn get_status(status: *mut Status) -> i32;
+    fn refresh_status(status: &mut Status);
+    // ok, only read by the C side
+    fn configure(options: *const Options);
+    // ok, no field with invalid values
+    fn get_validated(out: *mut Validated);
+}
+
+fn main() {}
@@ -0,0 +1,28 @@
+#![warn(clippy::ffi_unsafe_param_type)]
+#![allow(improper_ctypes, improper_ctypes_definitions)]
+
+use std::ffi::c_char;
+use std::num::NonZeroU32;
+use std::ptr::NonNull;
+
+extern "C" {
+    fn log_message(msg: &str);
+    fn take_bytes(bytes: Vec<u8>, pair: (i32, i32));
+    fn maybe_len(len: Option<usize>) -> String;
+    fn call(callback: &dyn Fn());
+
+    // ok
+    fn log_c_message(msg: *const c_char, len: usize);
+    fn maybe_ptr(ptr: Option<NonNull<u8>>, id: Option<NonZeroU32>) -> Option<&'static u8>;
+}
+
+extern "C" fn on_data(data: &[u8]) -> Option<u32> {
+    None
+}
+
+// ok
+extern "C" fn on_raw_data(data: *const u8, len: usize) {}
+
+fn rust_abi(msg: &str, pair: (i32, i32)) {}
+
+fn main() {}
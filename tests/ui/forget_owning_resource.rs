@@ -0,0 +1,45 @@
+#![warn(clippy::forget_owning_resource)]
+
+use std::fs::File;
+use std::mem::{self, ManuallyDrop};
+use std::net::TcpStream;
+use std::sync::Mutex;
+
+struct Wrapper {
+    file: File,
+}
+
+impl Drop for Wrapper {
+    fn drop(&mut self) {}
+}
+
+struct Buffer(Vec<u8>);
+
+fn forget(file: File, stream: TcpStream, mutex: &Mutex<i32>, wrapper: Wrapper, buffer: Buffer) {
+    mem::forget(file);
+    mem::forget(Some(stream));
+    mem::forget(mutex.lock().unwrap());
+    mem::forget(wrapper);
+
+    // Doesn't own a resource
+    mem::forget(buffer);
+}
+
+fn leak(file: File) -> &'static mut File {
+    Box::leak(Box::new(file))
+}
+
+fn manually_drop(a: File, b: File, c: File, d: File, e: File) -> File {
+    ManuallyDrop::new(a);
+    let _ = ManuallyDrop::new(b);
+    let leaked = ManuallyDrop::new(c);
+    let _ = leaked.metadata();
+
+    // Dropped or moved later
+    let mut dropped = ManuallyDrop::new(d);
+    unsafe { ManuallyDrop::drop(&mut dropped) };
+    let moved = ManuallyDrop::new(e);
+    ManuallyDrop::into_inner(moved)
+}
+
+fn main() {}
@@ -0,0 +1,44 @@
+#![warn(clippy::from_raw_parts_outlives_owner)]
+
+use std::slice;
+
+struct Holder {
+    data: &'static [u8],
+}
+
+fn static_binding() {
+    let tmp = vec![1u8, 2, 3];
+    let s: &'static [u8] = unsafe { slice::from_raw_parts(tmp.as_ptr(), tmp.len()) };
+
+    let array = [1u8, 2, 3];
+    let s: &'static [u8] = unsafe { slice::from_raw_parts(&array as *const u8, 3) };
+}
+
+fn field(holder: &mut Holder) {
+    let tmp = vec![1u8, 2, 3];
+    holder.data = unsafe { slice::from_raw_parts(tmp.as_ptr(), 3) };
+    let holder = Holder {
+        data: unsafe { slice::from_raw_parts(String::from("abc").as_ptr(), 3) },
+    };
+}
+
+fn returned(tmp: Vec<u8>) -> &'static [u8] {
+    unsafe { slice::from_raw_parts(tmp.as_ptr(), tmp.len()) }
+}
+
+fn returned_early(mut tmp: Vec<u8>) -> &'static mut [u8] {
+    if tmp.is_empty() {
+        return unsafe { slice::from_raw_parts_mut(tmp.as_mut_ptr(), 0) };
+    }
+    &mut []
+}
+
+fn no_escape(borrowed: &[u8], ptr: *const u8) -> &[u8] {
+    let tmp = vec![1u8, 2, 3];
+    let s = unsafe { slice::from_raw_parts(tmp.as_ptr(), tmp.len()) };
+    let s: &[u8] = unsafe { slice::from_raw_parts(tmp.as_ptr(), tmp.len()) };
+    let s: &'static [u8] = unsafe { slice::from_raw_parts(ptr, 3) };
+    unsafe { slice::from_raw_parts(borrowed.as_ptr(), borrowed.len()) }
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+#![warn(clippy::from_raw_parts_unbounded_lifetime)]
+#![allow(clippy::needless_lifetimes)]
+
+use std::slice;
+
+pub struct Buffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl Buffer {
+    pub unsafe fn data<'a>(&self) -> &'a [u8] {
+        //~^ ERROR: returning a slice created from raw parts with the unbounded lifetime `'a`
+        slice::from_raw_parts(self.ptr, self.len)
+    }
+
+    pub fn data_mut<'a>(&mut self) -> &'a mut [u8] {
+        //~^ ERROR: returning a slice created from raw parts with the unbounded lifetime `'a`
+        unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    // ok, tied to `self`
+    pub fn elided(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    pub fn named<'a>(&'a self) -> &'a [u8] {
+        unsafe { slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+pub unsafe fn prefix<'a>(buffer: &Buffer, len: usize) -> &'a [u8] {
+    //~^ ERROR: returning a slice created from raw parts with the unbounded lifetime `'a`
+    slice::from_raw_parts(buffer.ptr, len.min(buffer.len))
+}
+
+// ok, no input to tie the lifetime to
+pub unsafe fn from_parts<'a>(ptr: *const u8, len: usize) -> &'a [u8] {
+    slice::from_raw_parts(ptr, len)
+}
+
+fn main() {}
@@ -0,0 +1,63 @@
+#![warn(clippy::fs_toctou)]
+
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+fn open_existing(path: &Path) -> Option<File> {
+    if path.exists() {
+        return File::open(path).ok();
+        //~^ ERROR: `path` is used after being checked, but the filesystem may have changed in between
+    }
+    None
+}
+
+fn remove_if_file(name: &str) {
+    if fs::metadata(name).map_or(false, |meta| meta.is_file()) {
+        fs::remove_file(name).unwrap();
+        //~^ ERROR: `name` is used after being checked, but the filesystem may have changed in between
+    }
+}
+
+fn create_new(name: &str) {
+    if !Path::new(name).is_file() {
+        let _ = OpenOptions::new().write(true).create(true).open(name);
+        //~^ ERROR: `name` is used after being checked, but the filesystem may have changed in between
+    }
+}
+
+fn write_owned(path: PathBuf) {
+    if !path.is_dir() {
+        fs::write(&path, "data").unwrap();
+        //~^ ERROR: `path` is used after being checked, but the filesystem may have changed in between
+    }
+}
+
+// ok, the path is reassigned in between
+fn reassigned(mut path: PathBuf, fallback: PathBuf) {
+    if !path.exists() {
+        path = fallback;
+    }
+    let _ = File::open(&path);
+}
+
+// ok, the path is modified in between
+fn pushed(mut path: PathBuf) {
+    if path.is_dir() {
+        path.push("config.toml");
+    }
+    let _ = File::open(&path);
+}
+
+// ok, another path is used
+fn other_path(path: &Path, other: &Path) {
+    if path.exists() {
+        let _ = File::open(other);
+    }
+}
+
+// ok, the file is opened directly
+fn open_directly(path: &Path) -> Option<File> {
+    File::open(path).ok()
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#![warn(clippy::hardcoded_address_deref)]
+
+fn main() {
+    unsafe {
+        let value = *(1 as *const i32);
+        //~^ ERROR: dereferencing a pointer to the hardcoded address `0x1`
+        let p = 0x4000_0000 as *mut u32;
+        *p = value as u32;
+        //~^ ERROR: dereferencing a pointer to the hardcoded address `0x40000000`
+
+        // ok, not a constant address
+        let x = 5;
+        let addr = &x as *const i32 as usize;
+        let _ = *(addr as *const i32);
+        // ok, not cast from an integer
+        let _ = *(&x as *const i32);
+    }
+}
@@ -0,0 +1,39 @@
+#![feature(rustc_private)]
+#![warn(clippy::ignored_libc_return)]
+
+extern crate libc;
+
+use std::ffi::c_int;
+
+mod ffi {
+    use std::ffi::c_int;
+
+    extern "C" {
+        pub fn close(fd: c_int) -> c_int;
+        pub fn getpid() -> c_int;
+    }
+}
+
+fn main() {
+    let buf = [0u8; 4];
+    unsafe {
+        libc::write(1, buf.as_ptr().cast(), buf.len());
+        //~^ ERROR: the return value of this call is ignored
+        let _ = libc::close(3);
+        //~^ ERROR: the return value of this call is ignored
+        ffi::close(4);
+        //~^ ERROR: the return value of this call is ignored
+    }
+    let _ = unsafe { libc::close(5) };
+    //~^ ERROR: the return value of this call is ignored
+
+    // ok, the result is used
+    let written = unsafe { libc::write(1, buf.as_ptr().cast(), buf.len()) };
+    if unsafe { libc::close(6) } == -1 {
+        println!("{written}");
+    }
+    let _ret: c_int = unsafe { ffi::close(7) };
+
+    // ok, not a checked function
+    unsafe { ffi::getpid() };
+}
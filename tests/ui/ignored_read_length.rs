@@ -0,0 +1,48 @@
+#![feature(rustc_private)]
+#![warn(clippy::ignored_read_length)]
+
+extern crate libc;
+
+const BUF_SIZE: usize = 64;
+
+fn process(_data: &[u8]) {}
+
+fn capacity_local(fd: i32) {
+    let cap = 64;
+    let mut buf = [0u8; 64];
+    let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), cap) };
+    if n < 0 {
+        return;
+    }
+    process(&buf[..cap]);
+    //~^ ERROR: slicing the buffer up to its capacity after a read that may have filled it partially
+}
+
+fn capacity_const(fd: i32) -> Vec<u8> {
+    let mut buf = vec![0u8; BUF_SIZE];
+    let n = unsafe { libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, BUF_SIZE) } as usize;
+    let _ = n;
+    buf[0..BUF_SIZE].to_vec()
+    //~^ ERROR: slicing the buffer up to its capacity after a read that may have filled it partially
+}
+
+// ok, sliced up to the returned length
+fn returned_length(fd: i32) {
+    let cap = 64;
+    let mut buf = [0u8; 64];
+    let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), cap) };
+    if n > 0 {
+        process(&buf[..n as usize]);
+    }
+}
+
+// ok, another buffer
+fn other_buffer(fd: i32, other: &[u8]) {
+    let cap = 64;
+    let mut buf = [0u8; 64];
+    let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), cap) };
+    let _ = n;
+    process(&other[..cap]);
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+fn main() {
+    let len = std::env::args().count();
+    unsafe {
+        let _slice: &[usize] = std::slice::from_raw_parts(std::ptr::null(), 4);
+        //~^ ERROR: pointer must be non-null
+        let _slice: &[usize] = std::slice::from_raw_parts(std::ptr::null(), len);
+        //~^ ERROR: pointer must be non-null
+        let _slice: &mut [usize] = std::slice::from_raw_parts_mut(std::ptr::null_mut(), 4);
+        //~^ ERROR: pointer must be non-null
+    }
+}
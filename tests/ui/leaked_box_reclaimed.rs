@@ -0,0 +1,21 @@
+#![warn(clippy::leaked_box_reclaimed)]
+
+struct Config {
+    verbose: bool,
+}
+
+fn main() {
+    let config = Box::leak(Box::new(Config { verbose: true }));
+    config.verbose = false;
+    drop(unsafe { Box::from_raw(config) });
+    //~^ ERROR: reclaiming a `Box` that was leaked with `Box::leak`
+
+    let counter: &'static mut u32 = Box::leak(Box::new(0));
+    *counter += 1;
+    drop(unsafe { Box::from_raw(counter as *mut u32) });
+    //~^ ERROR: reclaiming a `Box` that was leaked with `Box::leak`
+
+    // ok, never leaked
+    let raw = Box::into_raw(Box::new(0u32));
+    drop(unsafe { Box::from_raw(raw) });
+}
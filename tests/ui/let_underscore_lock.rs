@@ -1,5 +1,6 @@
 #![warn(clippy::let_underscore_lock)]
 
+extern crate futures;
 extern crate parking_lot;
 
 fn main() {
@@ -42,3 +43,17 @@ fn uplifted() {
     let _ = m;
     let _ = rw;
 }
+
+async fn async_locks(m: &futures::lock::Mutex<()>, log: &std::cell::RefCell<Vec<i32>>) {
+    let _ = m.lock().await;
+    //~^ ERROR: non-binding `let` on a synchronization lock
+    // Meant to be protected by `m`, which is already unlocked here
+    log.borrow_mut().push(1);
+
+    let _ = m.try_lock();
+    //~^ ERROR: non-binding `let` on a synchronization lock
+
+    // This shouldn't throw an error.
+    let _guard = m.lock().await;
+    log.borrow_mut().push(2);
+}
@@ -0,0 +1,31 @@
+#![warn(clippy::mismatched_dealloc_layout)]
+
+use std::alloc::{alloc, alloc_zeroed, dealloc, Layout};
+
+fn main() {
+    unsafe {
+        let ptr = alloc(Layout::new::<u64>());
+        dealloc(ptr, Layout::new::<u32>());
+        //~^ ERROR: deallocating with a layout of size 4 and alignment 4, which differs from the layout used
+
+        let layout = Layout::array::<u16>(8).unwrap();
+        let ptr = alloc_zeroed(layout) as *mut u16;
+        dealloc(ptr as *mut u8, Layout::array::<u16>(4).unwrap());
+        //~^ ERROR: deallocating with a layout of size 8 and alignment 2, which differs from the layout used
+
+        let ptr = alloc(Layout::from_size_align(16, 8).unwrap());
+        dealloc(ptr, Layout::from_size_align(16, 4).unwrap());
+        //~^ ERROR: deallocating with a layout of size 16 and alignment 4, which differs from the layout used
+
+        // ok, the layouts are equivalent
+        let ptr = alloc(Layout::new::<u64>());
+        dealloc(ptr, Layout::from_size_align(8, 8).unwrap());
+        let ptr = alloc(layout);
+        dealloc(ptr, layout);
+
+        // ok, the layout can't be evaluated
+        let len = std::env::args().count();
+        let ptr = alloc(Layout::array::<u8>(len).unwrap());
+        dealloc(ptr, Layout::array::<u8>(4).unwrap());
+    }
+}
@@ -0,0 +1,38 @@
+#![feature(rustc_private)]
+#![warn(clippy::mismatched_resource_release)]
+
+extern crate libc;
+
+use std::mem::MaybeUninit;
+use std::ptr;
+
+fn mismatched() {
+    let mut first = libc::PTHREAD_MUTEX_INITIALIZER;
+    let mut second = libc::PTHREAD_MUTEX_INITIALIZER;
+    unsafe {
+        libc::pthread_mutex_init(&mut first, ptr::null());
+        libc::pthread_mutex_destroy(&mut second);
+        //~^ ERROR: releasing `&mut second` with `pthread_mutex_destroy`, but it isn't the handle
+    }
+
+    let mut sem = MaybeUninit::<libc::sem_t>::uninit();
+    let mut other = MaybeUninit::<libc::sem_t>::uninit();
+    unsafe {
+        libc::sem_init(sem.as_mut_ptr(), 0, 1);
+        libc::sem_destroy(other.as_mut_ptr());
+        //~^ ERROR: releasing `other.as_mut_ptr()` with `sem_destroy`, but it isn't the handle
+    }
+}
+
+fn matched() {
+    let mut cond = libc::PTHREAD_COND_INITIALIZER;
+    let mut lock = libc::PTHREAD_RWLOCK_INITIALIZER;
+    unsafe {
+        libc::pthread_cond_init(&mut cond, ptr::null());
+        libc::pthread_rwlock_init(&mut lock, ptr::null());
+        libc::pthread_rwlock_destroy(&mut lock);
+        libc::pthread_cond_destroy(ptr::addr_of_mut!(cond));
+    }
+}
+
+fn main() {}
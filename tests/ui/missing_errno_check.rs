@@ -0,0 +1,62 @@
+#![feature(rustc_private)]
+#![warn(clippy::missing_errno_check)]
+
+extern crate libc;
+
+use std::io;
+
+const PATH: &[u8] = b"/etc/hosts\0";
+
+fn read_unchecked(buf: &mut [u8]) -> isize {
+    let fd = unsafe { libc::open(PATH.as_ptr().cast(), libc::O_RDONLY) };
+    //~^ ERROR: the value returned by `open` isn't compared to `-1` before `errno` may change
+    unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) }
+}
+
+fn open_unchecked() {
+    let file = unsafe { libc::fopen(PATH.as_ptr().cast(), b"r\0".as_ptr().cast()) };
+    //~^ ERROR: the value returned by `fopen` is never compared to null
+    println!("{file:?}");
+}
+
+fn wrong_failure_value() -> io::Result<i32> {
+    let fd = unsafe { libc::open(PATH.as_ptr().cast(), libc::O_RDONLY) };
+    //~^ ERROR: the value returned by `open` is never compared to `-1`
+    if fd == 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(fd)
+}
+
+fn checked() -> io::Result<isize> {
+    let fd = unsafe { libc::open(PATH.as_ptr().cast(), libc::O_RDONLY) };
+    if fd == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    let mut buf = [0u8; 16];
+    let n = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+    if n < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let file = unsafe { libc::fopen(PATH.as_ptr().cast(), b"r\0".as_ptr().cast()) };
+    if file.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(n)
+}
+
+fn matched() -> io::Result<i32> {
+    let fd = unsafe { libc::socket(libc::AF_INET, libc::SOCK_STREAM, 0) };
+    match fd {
+        -1 => Err(io::Error::last_os_error()),
+        fd => Ok(fd),
+    }
+}
+
+fn handed_over() -> i32 {
+    let fd = unsafe { libc::open(PATH.as_ptr().cast(), libc::O_RDONLY) };
+    println!("opened {}", PATH.len());
+    fd
+}
+
+fn main() {}
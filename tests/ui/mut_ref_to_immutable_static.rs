@@ -0,0 +1,26 @@
+#![warn(clippy::mut_ref_to_immutable_static)]
+#![allow(invalid_reference_casting)]
+
+use std::ptr;
+use std::sync::atomic::AtomicU32;
+
+static LIMIT: u32 = 10;
+static COUNTER: AtomicU32 = AtomicU32::new(0);
+static mut SCRATCH: u32 = 0;
+
+fn main() {
+    unsafe {
+        let limit = &mut *(&LIMIT as *const u32 as *mut u32);
+        //~^ ERROR: creating a mutable reference to an immutable `static`
+        *limit = 20;
+
+        let p = ptr::addr_of!(LIMIT).cast_mut();
+        let _limit = p.as_mut();
+        //~^ ERROR: creating a mutable reference to an immutable `static`
+
+        // ok, the `static` has interior mutability
+        let _counter = &mut *(&COUNTER as *const AtomicU32 as *mut AtomicU32);
+        // ok, the `static` is mutable
+        let _scratch = &mut *ptr::addr_of_mut!(SCRATCH);
+    }
+}
@@ -0,0 +1,19 @@
+#![warn(clippy::offset_from_other_container)]
+
+fn main() {
+    let a = [1u8, 2, 3, 4];
+    let b = [0u8; 6];
+    let start = a.as_ptr();
+    let _len = unsafe { start.add(2).offset_from(b.as_ptr()) };
+    //~^ ERROR: computing the distance between pointers into `a` and `b`
+    let v = vec![1u32, 2, 3];
+    let w = vec![4u32, 5];
+    let end = w.as_ptr().wrapping_add(w.len());
+    let _len = unsafe { end.offset_from(v.as_ptr()) };
+    //~^ ERROR: computing the distance between pointers into `w` and `v`
+
+    // ok, both pointers are into the same container
+    let end = unsafe { start.add(a.len()) };
+    let _len = unsafe { end.offset_from(start) };
+    let _len = unsafe { v[1..].as_ptr().offset_from(v.as_ptr()) };
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::packed_field_aligned_access)]
+
+use std::ptr;
+
+#[repr(C, packed)]
+struct Header {
+    tag: u8,
+    len: u32,
+}
+
+#[repr(C, packed(4))]
+struct Aligned {
+    tag: u8,
+    len: u32,
+}
+
+fn main() {
+    let mut header = Header { tag: 1, len: 4 };
+    let _len = unsafe { ptr::addr_of!(header.len).read() };
+    //~^ ERROR: aligned read through a pointer to a field of a packed struct
+    let len = ptr::addr_of_mut!(header.len);
+    unsafe { ptr::write(len, 8) };
+    //~^ ERROR: aligned write through a pointer to a field of a packed struct
+
+    // ok, unaligned accesses
+    let _len = unsafe { ptr::addr_of!(header.len).read_unaligned() };
+    unsafe { len.write_unaligned(16) };
+    // ok, `u8` never needs to be aligned
+    let _tag = unsafe { ptr::addr_of!(header.tag).read() };
+    // ok, the packing is large enough for `u32`
+    let aligned = Aligned { tag: 1, len: 4 };
+    let _len = unsafe { ptr::addr_of!(aligned.len).read() };
+}
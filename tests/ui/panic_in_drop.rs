@@ -0,0 +1,73 @@
+#![warn(clippy::panic_in_drop)]
+
+struct Unwraps(Option<u32>);
+
+impl Drop for Unwraps {
+    fn drop(&mut self) {
+        //~^ ERROR: `Drop::drop` may panic
+        self.0.take().unwrap();
+    }
+}
+
+struct Expects(u64);
+
+impl Drop for Expects {
+    fn drop(&mut self) {
+        //~^ ERROR: `Drop::drop` may panic
+        u32::try_from(self.0).expect("too large");
+    }
+}
+
+struct Asserts(bool);
+
+impl Drop for Asserts {
+    fn drop(&mut self) {
+        //~^ ERROR: `Drop::drop` may panic
+        assert!(self.0, "not closed");
+    }
+}
+
+struct Indexes(Vec<u32>, usize);
+
+impl Drop for Indexes {
+    fn drop(&mut self) {
+        //~^ ERROR: `Drop::drop` may panic
+        let _ = self.0[self.1];
+    }
+}
+
+struct CalledClosure(Vec<u32>);
+
+impl Drop for CalledClosure {
+    fn drop(&mut self) {
+        //~^ ERROR: `Drop::drop` may panic
+        self.0.iter().for_each(|v| assert_ne!(*v, 0));
+    }
+}
+
+// ok, the closure is only stored
+struct StoredClosure(u32);
+
+impl Drop for StoredClosure {
+    fn drop(&mut self) {
+        let _callback = || panic!("never called");
+    }
+}
+
+// ok, indexing an array with a constant
+struct ConstIndex([u32; 4]);
+
+impl Drop for ConstIndex {
+    fn drop(&mut self) {
+        let _ = self.0[1];
+    }
+}
+
+// ok, not `Drop::drop`
+impl Unwraps {
+    fn close(mut self) -> u32 {
+        self.0.take().unwrap()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+#![warn(clippy::pinned_ref_cast_mut)]
+
+use std::pin::Pin;
+
+struct Node {
+    value: u32,
+}
+
+fn set(ptr: *mut Node, value: u32) {
+    unsafe { (*ptr).value = value };
+}
+
+fn via_get_ref(node: Pin<&Node>) {
+    let ptr = node.get_ref() as *const Node as *mut Node;
+    //~^ ERROR: casting a pointer derived from a pinned shared reference to a mutable pointer
+    set(ptr, 1);
+}
+
+fn via_as_ref(node: &Pin<Box<Node>>) {
+    let node_ref = node.as_ref().get_ref();
+    let ptr = node_ref as *const Node;
+    set(ptr as *mut Node, 2);
+    //~^ ERROR: casting a pointer derived from a pinned shared reference to a mutable pointer
+}
+
+fn via_deref(node: Pin<&Node>) {
+    set(&*node as *const Node as *mut Node, 3);
+    //~^ ERROR: casting a pointer derived from a pinned shared reference to a mutable pointer
+}
+
+fn no_lint(node: Pin<&mut Node>, other: &Node) {
+    // mutable access is granted by the `Pin`
+    set(unsafe { node.get_unchecked_mut() } as *mut Node, 4);
+    // not pinned
+    let ptr = other as *const Node;
+    let _ = ptr;
+}
+
+fn main() {}
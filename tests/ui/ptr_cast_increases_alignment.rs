@@ -0,0 +1,51 @@
+#![warn(clippy::ptr_cast_increases_alignment)]
+#![allow(clippy::cast_ptr_alignment, clippy::borrow_as_ptr, clippy::ptr_as_ptr)]
+
+#[repr(C)]
+struct Header {
+    tag: u16,
+    len: u16,
+}
+
+#[repr(C, align(8))]
+struct AlignedBuf([u8; 16]);
+
+fn main() {
+    let buf = [0u8; 16];
+
+    let _ = &buf as *const [u8; 16] as *const u8 as *const u32;
+    //~^ ERROR: casting a reference to `[u8; 16]` to a more-strictly-aligned pointer
+    let _ = &buf[0] as *const u8 as *const u64;
+    //~^ ERROR: casting a reference to `u8` to a more-strictly-aligned pointer
+
+    let header = Header { tag: 0, len: 0 };
+    let _ = &header as *const Header as *const u64;
+    //~^ ERROR: casting a reference to `Header` to a more-strictly-aligned pointer
+
+    // the referenced value is sufficiently aligned
+    let aligned = AlignedBuf([0; 16]);
+    let _ = &aligned as *const AlignedBuf as *const u8 as *const u64;
+    let _ = &header as *const Header as *const u16;
+
+    // the alignment is checked right away
+    let p = &buf as *const [u8; 16] as *const u32;
+    if p.align_offset(std::mem::align_of::<u32>()) == 0 {
+        let _ = unsafe { *p };
+    }
+    let p = &buf as *const [u8; 16] as *const u32;
+    assert_eq!(p as usize % std::mem::align_of::<u32>(), 0);
+    let _ = unsafe { (&buf as *const [u8; 16] as *const u32).read_unaligned() };
+
+    // the check is about another value
+    let p = &buf as *const [u8; 16] as *const u32;
+    //~^ ERROR: casting a reference to `[u8; 16]` to a more-strictly-aligned pointer
+    let (_, _, _) = unsafe { buf.align_to::<u32>() };
+    let q = &header as *const Header as *const u64;
+    //~^ ERROR: casting a reference to `Header` to a more-strictly-aligned pointer
+    let _ = p.align_offset(4);
+    let _ = q;
+
+    // raw pointers of unknown origin are left to `cast_ptr_alignment`
+    let raw = buf.as_ptr();
+    let _ = raw as *const u32;
+}
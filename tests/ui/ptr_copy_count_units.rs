@@ -0,0 +1,25 @@
+#![warn(clippy::ptr_copy_count_units)]
+
+use std::mem::size_of;
+use std::ptr;
+
+fn main() {
+    let src = [0u32; 8];
+    let mut dst = [0u32; 8];
+    let len = 4;
+
+    unsafe {
+        ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), len * size_of::<u16>());
+        //~^ ERROR: the count passed to `copy_nonoverlapping` is multiplied by the size of `u16`
+        ptr::write_bytes(dst.as_mut_ptr(), 0, size_of::<u64>());
+        //~^ ERROR: the count passed to `write_bytes` is multiplied by the size of `u64`
+        dst.as_mut_ptr().copy_from(src.as_ptr(), len * size_of::<u8>());
+        //~^ ERROR: the count passed to `copy_from` is multiplied by the size of `u8`
+
+        // ok, counts of elements
+        ptr::copy(src.as_ptr(), dst.as_mut_ptr(), len);
+        ptr::write_bytes(dst.as_mut_ptr(), 0, dst.len());
+        // ok, the pointee is a byte
+        ptr::copy_nonoverlapping(src.as_ptr().cast::<u8>(), dst.as_mut_ptr().cast::<u8>(), len * size_of::<u32>());
+    }
+}
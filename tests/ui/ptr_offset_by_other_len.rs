@@ -0,0 +1,19 @@
+#![warn(clippy::ptr_offset_by_other_len)]
+
+fn main() {
+    let a = [1u8, 2, 3, 4];
+    let b = [0u8; 6];
+    let p = a.as_ptr();
+    let _q = unsafe { p.add(b.len()) };
+    //~^ ERROR: offsetting a pointer into `a` by the length of `b`
+    let mut c = [0u8; 8];
+    let _end = c.as_mut_ptr().cast::<u16>().wrapping_add(b.len());
+    //~^ ERROR: offsetting a pointer into `c` by the length of `b`
+
+    // ok, offset by the length of the same container
+    let _end = unsafe { p.add(a.len()) };
+    let s = &c[..];
+    let _end = unsafe { s.as_ptr().add(s.len()) };
+    // ok, not offset by a length
+    let _second = unsafe { p.add(1) };
+}
@@ -0,0 +1,34 @@
+#![warn(clippy::ptr_offset_out_of_bounds)]
+
+unsafe fn zero(p: *mut u8, n: usize) {
+    for i in 0..n {
+        *p.add(i) = 0;
+        //~^ ERROR: offsetting a pointer by an amount that isn't checked against the length of the
+    }
+}
+
+unsafe fn at(p: *const u32, index: isize) -> u32 {
+    *p.offset(index)
+    //~^ ERROR: offsetting a pointer by an amount that isn't checked against the length of the
+}
+
+unsafe fn checked(buf: &mut [u8], n: usize) {
+    let p = buf.as_mut_ptr();
+    assert!(n <= buf.len());
+    for i in 0..n {
+        *p.add(i) = 0;
+    }
+    for i in 0..buf.len() {
+        *p.add(i) = 1;
+    }
+    let len = buf.len().min(n);
+    let _end = p.wrapping_add(len);
+
+    // small constant offsets
+    *p.add(1) = 2;
+    for i in 0..4 {
+        *p.add(i) = 3;
+    }
+}
+
+fn main() {}
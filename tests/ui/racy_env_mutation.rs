@@ -0,0 +1,26 @@
+#![feature(rustc_private)]
+#![warn(clippy::racy_env_mutation)]
+
+extern crate libc;
+
+use std::ffi::CString;
+use std::thread;
+
+fn set_timezone() {
+    let name = CString::new("TZ").unwrap();
+    let value = CString::new("UTC").unwrap();
+    unsafe { libc::setenv(name.as_ptr(), value.as_ptr(), 1) };
+    //~^ ERROR: calling `setenv` races with other threads accessing the environment
+}
+
+fn main() {
+    let worker = thread::spawn(|| {
+        let name = CString::new("HOME").unwrap();
+        unsafe { libc::getenv(name.as_ptr()) };
+    });
+    set_timezone();
+    let name = CString::new("LANG").unwrap();
+    unsafe { libc::unsetenv(name.as_ptr()) };
+    //~^ ERROR: calling `unsetenv` races with other threads accessing the environment
+    worker.join().unwrap();
+}
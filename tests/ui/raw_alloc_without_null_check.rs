@@ -0,0 +1,49 @@
+#![warn(clippy::raw_alloc_without_null_check)]
+
+use std::alloc::{alloc, alloc_zeroed, handle_alloc_error, realloc, Layout};
+use std::ptr::NonNull;
+
+unsafe fn unchecked(layout: Layout) {
+    let ptr = alloc(layout);
+    //~^ ERROR: the pointer returned by `alloc` is used without checking it for null
+    ptr.write_bytes(0, layout.size());
+
+    let ptr = realloc(ptr, layout, layout.size() * 2);
+    //~^ ERROR: the pointer returned by `realloc` is used without checking it for null
+    *ptr = 1;
+
+    let ptr = alloc(layout) as *mut u64;
+    //~^ ERROR: the pointer returned by `alloc` is used without checking it for null
+    ptr.write(0);
+
+    *(alloc_zeroed(layout) as *mut u32) = 1;
+    //~^ ERROR: the pointer returned by `alloc_zeroed` is dereferenced without checking it for null
+}
+
+unsafe fn checked(layout: Layout) {
+    let ptr = alloc(layout);
+    if ptr.is_null() {
+        handle_alloc_error(layout);
+    }
+    ptr.write_bytes(0, layout.size());
+
+    let ptr = alloc_zeroed(layout);
+    let Some(ptr) = NonNull::new(ptr) else {
+        handle_alloc_error(layout);
+    };
+
+    let ptr = alloc(layout) as *mut u64;
+    if ptr.is_null() {
+        handle_alloc_error(layout);
+    }
+    ptr.write(0);
+}
+
+// ok, the caller checks it
+unsafe fn returned(layout: Layout) -> *mut u8 {
+    let ptr = alloc_zeroed(layout);
+    println!("allocated {} bytes", layout.size());
+    ptr
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+#![warn(clippy::ref_from_unchecked_ptr)]
+
+use std::ptr::{self, NonNull};
+
+#[repr(C)]
+struct Config {
+    verbose: bool,
+}
+
+extern "C" {
+    fn get_config() -> *mut Config;
+}
+
+fn unchecked() {
+    let config = unsafe { get_config() };
+    let config = unsafe { &*config };
+    //~^ ERROR: creating a reference from a pointer received from foreign code without checking it
+    let config = unsafe { &mut *get_config() };
+    //~^ ERROR: creating a reference from a pointer received from foreign code without checking it
+}
+
+extern "C" fn callback(config: *const Config) {
+    let config = unsafe { &*config };
+    //~^ ERROR: creating a reference from a pointer received from foreign code without checking it
+}
+
+fn checked() {
+    let config = unsafe { get_config() };
+    if !config.is_null() {
+        let config = unsafe { &*config };
+    }
+
+    let config = unsafe { get_config() };
+    if NonNull::new(config).is_some() {
+        let config = unsafe { &mut *config };
+    }
+
+    let config = unsafe { get_config() };
+    if config.is_null() {
+        return;
+    }
+    let config = unsafe { &*config };
+}
+
+fn not_foreign(config: *const Config) {
+    let config = unsafe { &*config };
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+#![warn(clippy::ref_in_foreign_fn)]
+
+use std::ffi::c_char;
+
+#[repr(C)]
+pub struct Widget {
+    id: u32,
+}
+
+extern "C" {
+    fn frobnicate(widget: &Widget) -> &mut Widget;
+    //~^ ERROR: reference in the signature of a foreign function
+    //~| ERROR: reference in the signature of a foreign function
+    fn widget_name(widget: &Widget, buf: &mut c_char) -> *const c_char;
+    //~^ ERROR: reference in the signature of a foreign function
+    //~| ERROR: reference in the signature of a foreign function
+
+    // ok, raw pointers
+    fn widget_free(widget: *mut Widget);
+    fn widget_id(widget: *const Widget) -> u32;
+}
+
+// ok, not a C declaration
+extern "Rust" {
+    fn rust_widget(widget: &Widget) -> u32;
+}
+
+// ok, a definition
+extern "C" fn defined(widget: &Widget) -> u32 {
+    widget.id
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+#![warn(clippy::returned_closure_local_ptr)]
+
+fn unsound() -> impl Fn() -> i32 {
+    let x = 5;
+    let p = &x as *const i32;
+    move || unsafe { *p }
+}
+
+fn unsound_boxed() -> Box<dyn Fn() -> u8> {
+    let v = vec![1u8, 2, 3];
+    let p = v.as_ptr();
+    Box::new(move || unsafe { *p })
+}
+
+// ok, `x` is moved into the closure
+fn sound() -> impl Fn() -> i32 {
+    let x = 5;
+    move || unsafe { *(&x as *const i32) }
+}
+
+// ok, the buffer is moved into the closure along with the pointer
+fn sound_buffer() -> impl Fn() -> u8 {
+    let v = vec![1u8, 2, 3];
+    let p = v.as_ptr();
+    move || {
+        let _owner = &v;
+        unsafe { *p }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+#![warn(clippy::returned_ptr_to_local)]
+#![allow(temporary_cstring_as_ptr)]
+
+use std::ffi::{c_char, CString};
+
+fn local_string() -> *const u8 {
+    let s = String::from("hello");
+    s.as_ptr()
+}
+
+fn local_vec(n: usize) -> *mut u8 {
+    let mut v = vec![0u32; n];
+    v.as_mut_ptr() as *mut u8
+}
+
+fn param_cstring(s: CString) -> *const c_char {
+    s.as_bytes().as_ptr() as *const c_char
+}
+
+fn early_return(flag: bool) -> *const u8 {
+    let b: Box<[u8]> = Box::new([1, 2, 3]);
+    if flag {
+        return b.as_ptr();
+    }
+    std::ptr::null()
+}
+
+fn temporary(name: &str) -> *const c_char {
+    CString::new(name).unwrap().as_ptr()
+}
+
+// ok, the buffer outlives the function
+struct Holder {
+    buf: Vec<u8>,
+}
+
+impl Holder {
+    fn ptr(&self) -> *const u8 {
+        self.buf.as_ptr()
+    }
+}
+
+fn leaked() -> *const u8 {
+    let v = vec![1u8, 2, 3];
+    v.leak().as_ptr()
+}
+
+fn into_raw(name: &str) -> *mut c_char {
+    CString::new(name).unwrap().into_raw()
+}
+
+fn static_str() -> *const u8 {
+    "hello".as_ptr()
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+#![warn(clippy::rust_layout_byte_copy)]
+
+use std::mem::size_of;
+use std::ptr;
+
+struct Header {
+    kind: u8,
+    len: u32,
+}
+
+#[repr(C)]
+struct CHeader {
+    kind: u8,
+    len: u32,
+}
+
+#[repr(u8)]
+enum Kind {
+    A,
+    B,
+}
+
+enum RustKind {
+    A(u8),
+    B(u32),
+}
+
+fn main() {
+    let header = Header { kind: 1, len: 4 };
+    let mut buf = [0u8; 16];
+    unsafe {
+        ptr::copy_nonoverlapping(
+            &header as *const Header as *const u8,
+            //~^ ERROR: copying the bytes of `Header`, which has an unspecified layout
+            buf.as_mut_ptr(),
+            size_of::<Header>(),
+        );
+
+        ptr::copy(
+            &RustKind::B(2) as *const RustKind as *const u8,
+            //~^ ERROR: copying the bytes of `RustKind`, which has an unspecified layout
+            buf.as_mut_ptr(),
+            size_of::<RustKind>(),
+        );
+
+        // ok, `#[repr(C)]`
+        let c_header = CHeader { kind: 1, len: 4 };
+        ptr::copy_nonoverlapping(
+            &c_header as *const CHeader as *const u8,
+            buf.as_mut_ptr(),
+            size_of::<CHeader>(),
+        );
+
+        // ok, primitive representation
+        ptr::copy_nonoverlapping(&Kind::A as *const Kind as *const u8, buf.as_mut_ptr(), 1);
+
+        // ok, not a byte copy
+        let mut other = Header { kind: 0, len: 0 };
+        ptr::copy_nonoverlapping(&header, &mut other, 1);
+    }
+}
@@ -0,0 +1,33 @@
+#![warn(clippy::signed_len_to_ffi)]
+
+extern "C" {
+    fn malloc(size: usize) -> *mut u8;
+    fn memset(dest: *mut u8, c: i32, n: usize) -> *mut u8;
+}
+
+extern "C" fn defined_in_rust(len: usize) {}
+
+fn rust_fn(len: usize) {}
+
+const LEN: i32 = 16;
+const NEGATIVE: i32 = -1;
+
+fn main() {
+    let len: i32 = 32;
+    let count: i64 = 8;
+    let v = vec![1u8, 2, 3];
+    unsafe {
+        let p = malloc(len as usize);
+        memset(p, 0, count as usize);
+        defined_in_rust(len as usize);
+        malloc(NEGATIVE as usize);
+
+        // Known to be non-negative
+        malloc(3);
+        malloc(LEN as usize);
+        malloc(v.len() as i32 as usize);
+        memset(p, len, v.len());
+    }
+    // Not a foreign ABI
+    rust_fn(len as usize);
+}
@@ -39,3 +39,9 @@ fn main() {
     // Different types for pointee and size_of (Should not trigger the lint)
     unsafe { y.as_mut_ptr().write_bytes(0u8, size_of::<u16>() / 2 * SIZE) };
 }
+
+// Count expression involving the size of a generic pointee (Should trigger the lint)
+unsafe fn copy_generic<T>(src: *const T, dst: *mut T, n: usize) {
+    copy(src, dst, n * size_of::<T>());
+    //~^ ERROR: found a count of bytes instead of a count of elements of `T`
+}
@@ -0,0 +1,34 @@
+#![warn(clippy::size_of_pointer_in_byte_count)]
+
+use std::alloc::Layout;
+use std::ffi::c_void;
+use std::mem::{align_of, size_of};
+
+extern "C" {
+    fn malloc(size: usize) -> *mut c_void;
+    fn calloc(count: usize, size: usize) -> *mut c_void;
+    fn memcpy(dst: *mut c_void, src: *const c_void, size: usize) -> *mut c_void;
+}
+
+struct Header {
+    len: u64,
+    cap: u64,
+}
+
+fn main() {
+    unsafe {
+        let header = malloc(size_of::<*const Header>()).cast::<Header>();
+        //~^ ERROR: passing the size of the pointer `*const Header` to `malloc`
+        let headers = calloc(4, size_of::<&Header>()).cast::<Header>();
+        //~^ ERROR: passing the size of the pointer `&Header` to `calloc`
+        memcpy(header.cast(), headers.cast(), 2 * size_of::<*mut Header>());
+        //~^ ERROR: passing the size of the pointer `*mut Header` to `memcpy`
+        let _ = Layout::from_size_align(size_of::<&dyn Fn()>(), align_of::<usize>());
+        //~^ ERROR: passing the size of the pointer `&dyn std::ops::Fn()` to `from_size_align`
+
+        // ok, the size of the pointee
+        let header = malloc(size_of::<Header>()).cast::<Header>();
+        // ok, not a byte count
+        let headers = calloc(size_of::<*const Header>(), size_of::<Header>());
+    }
+}
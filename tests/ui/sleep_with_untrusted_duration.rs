@@ -0,0 +1,42 @@
+#![warn(clippy::sleep_with_untrusted_duration)]
+
+use std::thread;
+use std::time::Duration;
+
+const MAX_DELAY: u64 = 60;
+
+fn decoded(header: [u8; 8]) {
+    thread::sleep(Duration::from_secs(u64::from_le_bytes(header)));
+    //~^ ERROR: sleeping for a duration decoded from raw bytes
+
+    let millis = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+    let delay = Duration::from_millis(millis as u64);
+    thread::sleep(delay);
+    //~^ ERROR: sleeping for a duration decoded from raw bytes
+}
+
+fn signed(retry_after: i64) {
+    thread::sleep(Duration::from_secs(retry_after as u64));
+    //~^ ERROR: sleeping for a duration cast from a signed integer
+}
+
+fn capped(header: [u8; 8], retry_after: i64) {
+    thread::sleep(Duration::from_secs(u64::from_le_bytes(header).min(MAX_DELAY)));
+
+    let secs = u64::from_le_bytes(header).clamp(1, MAX_DELAY);
+    thread::sleep(Duration::from_secs(secs));
+
+    thread::sleep(Duration::from_secs(u64::from_le_bytes(header)).min(Duration::from_secs(MAX_DELAY)));
+
+    if retry_after < 0 || retry_after as u64 > MAX_DELAY {
+        return;
+    }
+    thread::sleep(Duration::from_secs(retry_after as u64));
+}
+
+fn trusted(secs: u64) {
+    thread::sleep(Duration::from_secs(5));
+    thread::sleep(Duration::from_secs(secs));
+}
+
+fn main() {}
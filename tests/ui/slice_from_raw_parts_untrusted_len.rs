@@ -0,0 +1,37 @@
+#![warn(clippy::slice_from_raw_parts_untrusted_len)]
+
+use std::slice;
+
+unsafe fn decoded(buf: &[u8], header: [u8; 4]) -> &[u8] {
+    let len = u32::from_le_bytes(header) as usize;
+    slice::from_raw_parts(buf.as_ptr().add(4), len)
+    //~^ ERROR: creating a slice from raw parts with a length decoded from raw bytes
+}
+
+unsafe fn signed(p: *mut u16, count: i32) {
+    let len = count as usize;
+    slice::from_raw_parts_mut(p, len).fill(0);
+    //~^ ERROR: creating a slice from raw parts with a length cast from a signed integer
+}
+
+unsafe fn decoded_checked(buf: &[u8], header: [u8; 4]) -> &[u8] {
+    let len = u32::from_le_bytes(header) as usize;
+    if len > buf.len() - 4 {
+        return &[];
+    }
+    slice::from_raw_parts(buf.as_ptr().add(4), len)
+}
+
+unsafe fn signed_checked(p: *mut u16, count: i32) {
+    if count < 0 {
+        return;
+    }
+    slice::from_raw_parts_mut(p, count as usize).fill(0);
+}
+
+unsafe fn trusted(p: *const u8, other: &[u8]) -> u8 {
+    let copy = slice::from_raw_parts(p, other.len());
+    copy.iter().fold(0, |acc, b| acc ^ b)
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![warn(clippy::transmute_copy_to_larger_type)]
+
+use std::mem::transmute_copy;
+
+#[repr(C)]
+struct Header {
+    tag: u8,
+    len: u16,
+}
+
+fn main() {
+    let small: u32 = 1;
+    let array = [1u8, 2];
+    unsafe {
+        let _: u64 = transmute_copy(&small);
+        //~^ ERROR: `transmute_copy` from `u32` (4 bytes) to the larger `u64` (8 bytes)
+        let _ = transmute_copy::<[u8; 2], Header>(&array);
+        //~^ ERROR: `transmute_copy` from `[u8; 2]` (2 bytes) to the larger `Header` (4 bytes)
+
+        // Same size or smaller
+        let _: i32 = transmute_copy(&small);
+        let _: u16 = transmute_copy(&small);
+        let _: [u8; 4] = transmute_copy(&small);
+    }
+}
@@ -0,0 +1,38 @@
+#![warn(clippy::transmute_fn_ptr_qualifiers)]
+
+unsafe extern "C" fn foreign(x: i32) -> i32 {
+    x
+}
+
+unsafe fn unsafe_rust(x: i32) -> i32 {
+    x
+}
+
+extern "C" fn safe_foreign(x: i32) -> i32 {
+    x
+}
+
+fn plain(x: i32) -> i32 {
+    x
+}
+
+fn main() {
+    let ptr: unsafe extern "C" fn(i32) -> i32 = foreign;
+    unsafe {
+        let f: fn(i32) -> i32 = std::mem::transmute(ptr);
+        f(1);
+        let g = std::mem::transmute::<unsafe fn(i32) -> i32, fn(i32) -> i32>(unsafe_rust);
+        g(2);
+        std::mem::transmute::<extern "C" fn(i32) -> i32, fn(i32) -> i32>(safe_foreign)(3);
+    }
+    let h: fn(i32) -> i32 = unsafe { std::mem::transmute(ptr) };
+    h(4);
+
+    // Not called
+    let _stored: fn(i32) -> i32 = unsafe { std::mem::transmute(ptr) };
+
+    // Adding `unsafe` is fine
+    let safe: fn(i32) -> i32 = plain;
+    let u: unsafe fn(i32) -> i32 = unsafe { std::mem::transmute(safe) };
+    unsafe { u(5) };
+}
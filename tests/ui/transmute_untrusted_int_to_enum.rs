@@ -0,0 +1,34 @@
+#![warn(clippy::transmute_untrusted_int_to_enum)]
+#![allow(dead_code)]
+
+use std::mem;
+
+#[repr(u32)]
+enum Kind {
+    Request = 1,
+    Response = 2,
+}
+
+fn decoded(header: [u8; 4]) {
+    let _: Kind = unsafe { mem::transmute::<u32, Kind>(u32::from_le_bytes(header)) };
+    //~^ ERROR: transmuting an integer decoded from raw bytes into the enum `Kind`
+
+    let raw = u32::from_be_bytes(header);
+    let _: Kind = unsafe { mem::transmute_copy(&raw) };
+    //~^ ERROR: transmuting an integer decoded from raw bytes into the enum `Kind`
+}
+
+fn checked(header: [u8; 4]) {
+    let raw = u32::from_le_bytes(header);
+    if raw == 0 || raw > 2 {
+        return;
+    }
+    let _: Kind = unsafe { mem::transmute(raw) };
+}
+
+fn trusted(raw: u32) {
+    let _: Kind = unsafe { mem::transmute(raw) };
+    let _: Kind = unsafe { mem::transmute(1u32) };
+}
+
+fn main() {}
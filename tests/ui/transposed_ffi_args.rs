@@ -0,0 +1,24 @@
+#![warn(clippy::transposed_ffi_args)]
+
+extern "C" {
+    fn read(fd: i32, buf: *mut u8, count: usize) -> isize;
+    fn memset(dest: *mut u8, c: i32, n: usize) -> *mut u8;
+    fn set_user_data(handle: i32, data: isize);
+}
+
+fn main() {
+    let mut buf = vec![0u8; 64];
+    let len = buf.len();
+    unsafe {
+        read(0, len as *mut u8, buf.as_mut_ptr() as usize);
+        memset(buf.as_mut_ptr(), 64, 0);
+
+        // Arguments in the right order
+        read(0, buf.as_mut_ptr(), len);
+        memset(buf.as_mut_ptr(), 0, len);
+        memset(buf.as_mut_ptr(), 0, 0);
+
+        // ok, not a configured function, passing a pointer as an integer is how its API works
+        set_user_data(0, buf.as_mut_ptr() as isize);
+    }
+}
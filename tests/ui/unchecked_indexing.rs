@@ -0,0 +1,62 @@
+#![warn(clippy::unchecked_indexing)]
+#![allow(clippy::ptr_arg)]
+
+unsafe fn unchecked(values: &[u32], vec: &Vec<u32>, n: usize) -> u32 {
+    let array = [1, 2, 3];
+    values[n] + vec[n + 1] + array[n]
+    //~^ ERROR: indexing with an index that isn't bounds-checked
+    //~| ERROR: indexing with an index that isn't bounds-checked
+    //~| ERROR: indexing with an index that isn't bounds-checked
+}
+
+extern "C" fn in_extern(values: *const u32, len: usize, n: usize) -> u32 {
+    let values = unsafe { std::slice::from_raw_parts(values, len) };
+    let f = || values[n];
+    //~^ ERROR: indexing with an index that isn't bounds-checked
+    f()
+}
+
+unsafe fn checked(values: &[u32], n: usize, m: usize) -> u32 {
+    if n >= values.len() {
+        return 0;
+    }
+    assert!(m < values.len());
+    values[n] + values[m]
+}
+
+unsafe fn checked_with_get(values: &[u32], n: usize) -> u32 {
+    if values.get(n).is_none() {
+        return 0;
+    }
+    values[n]
+}
+
+unsafe fn checked_with_len_local(values: &[u32], n: usize) -> u32 {
+    let len = values.len();
+    if n < len { values[n] } else { 0 }
+}
+
+unsafe fn compared_with_something_else(values: &[u32], other: &[u32], n: usize) -> u32 {
+    if n >= other.len() || n == 0 || other.get(n).is_none() {
+        return 0;
+    }
+    values[n]
+    //~^ ERROR: indexing with an index that isn't bounds-checked
+}
+
+unsafe fn last(values: &[u32]) -> u32 {
+    let last = values.len() - 1;
+    values[values.len() - 1] + values[last]
+}
+
+unsafe fn constant(values: &[u32]) -> u32 {
+    const IDX: usize = 2;
+    values[0] + values[IDX]
+}
+
+// ok, not `unsafe` or `extern`
+fn safe(values: &[u32], n: usize) -> u32 {
+    values[n]
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+#![warn(clippy::uninit_alloc_field_read)]
+
+use std::mem::size_of;
+use std::ptr::addr_of_mut;
+
+extern "C" {
+    fn malloc(size: usize) -> *mut u8;
+    fn init_point(point: *mut Point);
+}
+
+#[repr(C)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+unsafe fn partially_written() -> i32 {
+    let p = malloc(size_of::<Point>()) as *mut Point;
+    if p.is_null() {
+        return 0;
+    }
+    (*p).x = 1;
+    (*p).x + (*p).y
+    //~^ ERROR: reading the field `y` of a struct allocated with `malloc` before writing it
+}
+
+unsafe fn never_written() -> i32 {
+    let p = malloc(size_of::<Point>()) as *mut Point;
+    (*p).x += 1;
+    //~^ ERROR: reading the field `x` of a struct allocated with `malloc` before writing it
+    (*p).x
+}
+
+// ok, all the fields read were written first
+unsafe fn fully_written() -> i32 {
+    let p = malloc(size_of::<Point>()) as *mut Point;
+    (*p).x = 1;
+    addr_of_mut!((*p).y).write(2);
+    (*p).x + (*p).y
+}
+
+// ok, initialized as a whole
+unsafe fn written_whole() -> i32 {
+    let p = malloc(size_of::<Point>()) as *mut Point;
+    p.write(Point { x: 1, y: 2 });
+    (*p).y
+}
+
+// ok, may be initialized by the callee
+unsafe fn initialized_by_call() -> i32 {
+    let p = malloc(size_of::<Point>()) as *mut Point;
+    init_point(p);
+    (*p).y
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+#![warn(clippy::unterminated_vec_as_c_string)]
+
+use std::ffi::{c_char, c_void};
+
+extern "C" {
+    fn puts(s: *const c_char) -> i32;
+    fn write(fd: i32, buf: *const c_void, count: usize) -> isize;
+}
+
+fn main() {
+    let mut name = Vec::new();
+    name.extend_from_slice(b"HOME");
+    unsafe { puts(name.as_ptr().cast()) };
+    //~^ ERROR: passing a byte buffer that is not known to be NUL-terminated as a C string
+
+    let bytes = vec![b'h', b'i'];
+    let ptr = bytes.as_ptr() as *const c_char;
+    unsafe { puts(ptr) };
+    //~^ ERROR: passing a byte buffer that is not known to be NUL-terminated as a C string
+
+    // ok, a NUL byte was pushed
+    let mut pushed = Vec::new();
+    pushed.extend_from_slice(b"HOME");
+    pushed.push(0);
+    unsafe { puts(pushed.as_ptr().cast()) };
+
+    // ok, created NUL-terminated
+    let terminated = vec![b'h', b'i', 0];
+    unsafe { puts(terminated.as_ptr().cast()) };
+    let owned = b"hi\0".to_vec();
+    unsafe { puts(owned.as_ptr().cast()) };
+    let zeroed = vec![0u8; 16];
+    unsafe { puts(zeroed.as_ptr().cast()) };
+
+    // ok, not passed as a C string
+    unsafe { write(1, bytes.as_ptr().cast(), bytes.len()) };
+}
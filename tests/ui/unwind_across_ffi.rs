@@ -0,0 +1,45 @@
+#![feature(c_unwind)]
+#![warn(clippy::unwind_across_ffi)]
+#![allow(improper_ctypes_definitions)]
+
+extern "C" fn unwraps(value: Option<u32>) -> u32 {
+    //~^ ERROR: this `extern "C"` function may panic
+    value.unwrap()
+}
+
+extern "C" fn adds(a: u32, b: u32) -> u32 {
+    //~^ ERROR: this `extern "C"` function may panic
+    a + b
+}
+
+extern "system" fn indexes(values: *const u32, len: usize, i: usize) -> u32 {
+    //~^ ERROR: this `extern "system"` function may panic
+    unsafe { std::slice::from_raw_parts(values, len)[i] }
+}
+
+extern "C" fn asserts(value: u32) {
+    //~^ ERROR: this `extern "C"` function may panic
+    assert_ne!(value, 0);
+}
+
+// ok, unwinding is allowed
+extern "C-unwind" fn unwinds(value: Option<u32>) -> u32 {
+    value.unwrap()
+}
+
+// ok, the panic is caught
+extern "C" fn caught(value: Option<u32>) -> u32 {
+    std::panic::catch_unwind(|| value.unwrap()).unwrap_or(0)
+}
+
+// ok, not a foreign ABI
+fn rust(value: Option<u32>) -> u32 {
+    value.unwrap()
+}
+
+// ok, constant arithmetic
+extern "C" fn constant() -> u32 {
+    2 * 3
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![feature(rustc_private)]
+#![warn(clippy::use_of_system_call)]
+
+extern crate libc;
+
+use std::ffi::CString;
+use std::process::Command;
+
+fn main() {
+    let command = CString::new("ls -l /tmp").unwrap();
+    let mode = CString::new("r").unwrap();
+    let program = CString::new("ls").unwrap();
+    unsafe {
+        libc::system(command.as_ptr());
+        //~^ ERROR: calling `system` runs a command through the shell
+        let stream = libc::popen(command.as_ptr(), mode.as_ptr());
+        //~^ ERROR: calling `popen` runs a command through the shell
+        libc::pclose(stream);
+        libc::execlp(program.as_ptr(), program.as_ptr(), std::ptr::null::<libc::c_char>());
+        //~^ ERROR: calling `execlp` runs an external command
+    }
+
+    // ok, no shell involved
+    let _ = Command::new("ls").args(["-l", "/tmp"]).status();
+}
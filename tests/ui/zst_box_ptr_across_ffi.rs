@@ -0,0 +1,49 @@
+#![warn(clippy::zst_box_ptr_across_ffi)]
+#![allow(improper_ctypes_definitions)]
+
+use std::ffi::c_void;
+
+struct Marker;
+
+extern "C" fn into_handle<T>(value: T) -> *mut c_void {
+    Box::into_raw(Box::new(value)).cast()
+    //~^ ERROR: returning a pointer to a `Box` allocation of `T`, which may be zero-sized
+}
+
+extern "C" fn into_handle_as<T>(value: T) -> *mut c_void {
+    let ptr = Box::into_raw(Box::new(value));
+    ptr as *mut c_void
+    //~^ ERROR: returning a pointer to a `Box` allocation of `T`, which may be zero-sized
+}
+
+extern "C" fn new_marker() -> *mut Marker {
+    Box::into_raw(Box::new(Marker))
+    //~^ ERROR: returning a pointer to a `Box` allocation of the zero-sized type `Marker`
+}
+
+extern "C" fn maybe_handle<T>(value: Option<T>) -> *mut c_void {
+    match value {
+        Some(value) => return Box::into_raw(Box::new(value)).cast(),
+        //~^ ERROR: returning a pointer to a `Box` allocation of `T`, which may be zero-sized
+        None => std::ptr::null_mut(),
+    }
+}
+
+// ok, not zero-sized
+extern "C" fn new_counter() -> *mut u64 {
+    Box::into_raw(Box::new(0))
+}
+
+// ok, not an `extern` function
+fn rust_handle<T>(value: T) -> *mut T {
+    Box::into_raw(Box::new(value))
+}
+
+fn main() {
+    let _ = into_handle(1u8);
+    let _ = into_handle_as(1u8);
+    let _ = new_marker();
+    let _ = maybe_handle(Some(1u8));
+    let _ = new_counter();
+    let _ = rust_handle(1u8);
+}